@@ -0,0 +1,257 @@
+use clap::{Parser, Subcommand};
+use dpp::dashcore::secp256k1::hashes::hex::DisplayHex;
+use dpp::dashcore::secp256k1::Secp256k1;
+use dpp::identifier::Identifier;
+use dpp::platform_value::string_encoding::Encoding::Base58;
+use log::info;
+use serde_json::{json, Value};
+use crate::context::Context;
+use crate::errors::cli_argument_invalid_input::CommandLineArgumentInvalidInput;
+use crate::errors::cli_argument_missing_error::CommandLineArgumentMissingError;
+use crate::errors::Error;
+use crate::errors::keystore_error::KeystoreError;
+use crate::keystore::Keystore;
+use crate::output::Report;
+use crate::utils::Utils;
+use crate::wallet::Wallet;
+
+/// Manage private keys in the password-encrypted local keystore
+#[derive(Parser)]
+pub struct KeysCommand {
+    #[command(subcommand)]
+    cmd: KeysSubcommand,
+
+    /// Enable verbose logging for a debugging
+    #[clap(long, global = true)]
+    pub verbose: bool,
+}
+
+#[derive(Subcommand)]
+enum KeysSubcommand {
+    Import(ImportKeyCommand),
+    List(ListKeysCommand),
+    Remove(RemoveKeyCommand),
+    Export(ExportKeyCommand),
+    Derive(DeriveKeysCommand),
+}
+
+/// Encrypt a private key from the file and put it in the keystore under a label
+#[derive(Parser)]
+pub struct ImportKeyCommand {
+    /// Label to reference the key in other commands with --key
+    #[clap(long, default_value(""))]
+    label: String,
+
+    /// Network, mainnet or testnet
+    #[clap(long, env = "PLATFORM_CLI_NETWORK", default_value(""))]
+    network: String,
+
+    /// Path to file with private key in WIF, hex or base64 format
+    #[clap(long, default_value(""))]
+    private_key: String,
+
+    /// Identity the key belongs to
+    #[clap(long, default_value(""))]
+    identity: String,
+
+    /// Id of the key in the Identity
+    #[clap(long)]
+    key_id: Option<u32>,
+}
+
+/// List keys stored in the keystore
+#[derive(Parser)]
+pub struct ListKeysCommand {}
+
+/// Remove a key from the keystore
+#[derive(Parser)]
+pub struct RemoveKeyCommand {
+    /// Label of the key
+    #[clap(long, default_value(""))]
+    label: String,
+}
+
+/// Decrypt a key from the keystore and print it in WIF format
+#[derive(Parser)]
+pub struct ExportKeyCommand {
+    /// Label of the key
+    #[clap(long, default_value(""))]
+    label: String,
+}
+
+/// Derive identity authentication keys from a BIP39 mnemonic along the DIP-13 path
+#[derive(Parser)]
+pub struct DeriveKeysCommand {
+    /// Network, mainnet or testnet
+    #[clap(long, env = "PLATFORM_CLI_NETWORK", default_value(""))]
+    network: String,
+
+    /// Path to file with BIP39 mnemonic, or - to enter it in the terminal
+    #[clap(long, env = "PLATFORM_CLI_MNEMONIC", default_value(""))]
+    mnemonic: String,
+
+    /// Index of the identity in the wallet
+    #[clap(long, default_value("0"))]
+    identity_index: u32,
+
+    /// Amount of keys to derive
+    #[clap(long, default_value("2"))]
+    count: u32,
+}
+
+impl KeysCommand {
+    pub async fn run(&self, context: &Context) -> Result<Report, Error> {
+        match &self.cmd {
+            KeysSubcommand::Import(x) => x.run(context),
+            KeysSubcommand::List(x) => x.run(context),
+            KeysSubcommand::Remove(x) => x.run(context),
+            KeysSubcommand::Export(x) => x.run(context),
+            KeysSubcommand::Derive(x) => x.run(),
+        }
+    }
+}
+
+impl ImportKeyCommand {
+    pub fn run(&self, context: &Context) -> Result<Report, Error> {
+        if self.label.is_empty() {
+            return Err(Error::CommandLineArgumentMissingError(CommandLineArgumentMissingError::from("label")));
+        }
+
+        if self.network.is_empty() {
+            return Err(Error::CommandLineArgumentMissingError(CommandLineArgumentMissingError::from("network")));
+        }
+
+        if self.private_key.is_empty() {
+            return Err(Error::CommandLineArgumentMissingError(CommandLineArgumentMissingError::from("private_key")));
+        }
+
+        let network_type = Utils::parse_network(&self.network)?;
+        let private_key = Utils::read_private_key_file(&self.private_key, network_type)?;
+        let public_key = private_key.public_key(&Secp256k1::new());
+
+        let identity = match self.identity.is_empty() {
+            true => None,
+            false => Some(Identifier::from_string(&self.identity, Base58)
+                .map_err(|_| Error::CommandLineArgumentInvalidInput(CommandLineArgumentInvalidInput::from("Could not parse identity identifier")))?)
+        };
+
+        let mut keystore = Keystore::open(&context.keystore_path)?;
+
+        let password = Keystore::read_password("New keystore password: ")?;
+        let password_confirmation = Keystore::read_password("Repeat password: ")?;
+
+        if password != password_confirmation {
+            return Err(Error::KeystoreError(KeystoreError::from("passwords do not match")));
+        }
+
+        keystore.import(&self.label, &private_key, public_key.to_bytes().as_slice(), identity, self.key_id, &password)?;
+        keystore.save()?;
+
+        info!("Key {} has been imported into {}", &self.label, context.keystore_path.display());
+        info!("You can now delete the plaintext private key file {}", &self.private_key);
+
+        Ok(Report::with_data(json!({ "label": self.label, "public_key": public_key.to_bytes().to_lower_hex_string() })))
+    }
+}
+
+impl ListKeysCommand {
+    pub fn run(&self, context: &Context) -> Result<Report, Error> {
+        let keystore = Keystore::open(&context.keystore_path)?;
+
+        if keystore.entries().is_empty() {
+            info!("No keys in the keystore {}", context.keystore_path.display());
+        }
+
+        for entry in keystore.entries() {
+            info!("{}: network: {}, identity: {}, key id: {}, public key: {}",
+                entry.label,
+                entry.network,
+                entry.identity.clone().unwrap_or(String::from("-")),
+                entry.key_id.map(|key_id| key_id.to_string()).unwrap_or(String::from("-")),
+                entry.public_key);
+        }
+
+        let keys = keystore.entries()
+            .iter()
+            .map(|entry| json!({
+                "label": entry.label,
+                "network": entry.network,
+                "identity": entry.identity,
+                "key_id": entry.key_id,
+                "public_key": entry.public_key,
+            }))
+            .collect::<Vec<Value>>();
+
+        Ok(Report::with_data(json!({ "keys": keys })))
+    }
+}
+
+impl RemoveKeyCommand {
+    pub fn run(&self, context: &Context) -> Result<Report, Error> {
+        if self.label.is_empty() {
+            return Err(Error::CommandLineArgumentMissingError(CommandLineArgumentMissingError::from("label")));
+        }
+
+        let mut keystore = Keystore::open(&context.keystore_path)?;
+
+        keystore.remove(&self.label)?;
+        keystore.save()?;
+
+        info!("Key {} has been removed from the keystore", &self.label);
+
+        Ok(Report::with_data(json!({ "label": self.label })))
+    }
+}
+
+impl ExportKeyCommand {
+    pub fn run(&self, context: &Context) -> Result<Report, Error> {
+        if self.label.is_empty() {
+            return Err(Error::CommandLineArgumentMissingError(CommandLineArgumentMissingError::from("label")));
+        }
+
+        let keystore = Keystore::open(&context.keystore_path)?;
+        let private_key = keystore.unlock(&self.label, None, None)?;
+
+        info!("{}", private_key.to_wif());
+
+        Ok(Report::with_data(json!({ "label": self.label, "private_key": private_key.to_wif() })))
+    }
+}
+
+impl DeriveKeysCommand {
+    pub fn run(&self) -> Result<Report, Error> {
+        if self.network.is_empty() {
+            return Err(Error::CommandLineArgumentMissingError(CommandLineArgumentMissingError::from("network")));
+        }
+
+        if self.mnemonic.is_empty() {
+            return Err(Error::CommandLineArgumentMissingError(CommandLineArgumentMissingError::from("mnemonic")));
+        }
+
+        let secp = Secp256k1::new();
+        let network_type = Utils::parse_network(&self.network)?;
+        let wallet = Wallet::from_mnemonic_file(&self.mnemonic, network_type)?;
+
+        let mut keys: Vec<Value> = Vec::new();
+
+        for key_index in 0..self.count {
+            let derived_key = wallet.derive_identity_authentication_key(self.identity_index, key_index)?;
+            let public_key = derived_key.private_key.public_key(&secp);
+
+            info!("Key {} ({}): public key: {}, pubkeyhash: {}",
+                derived_key.key_index,
+                derived_key.path,
+                public_key.to_bytes().to_lower_hex_string(),
+                public_key.pubkey_hash().to_hex());
+
+            keys.push(json!({
+                "key_index": derived_key.key_index,
+                "path": derived_key.path.to_string(),
+                "public_key": public_key.to_bytes().to_lower_hex_string(),
+                "pubkeyhash": public_key.pubkey_hash().to_hex(),
+            }));
+        }
+
+        Ok(Report::with_data(json!({ "keys": keys })))
+    }
+}
@@ -1,3 +1,11 @@
 pub mod register_dpns_name;
 pub mod withdraw;
 pub mod masternode_vote_dpns_name;
+pub mod keys;
+pub mod withdrawal_status;
+pub mod transfer_credits;
+pub mod update_identity;
+pub mod masternode_identity;
+pub mod batch;
+pub mod dpns_name;
+pub mod name_status;
@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+use rs_dapi_client::RequestSettings;
+use serde::Deserialize;
+use crate::errors::config_file_error::ConfigFileError;
+use crate::errors::Error;
+use crate::flags::GlobalFlags;
+use crate::grpc::DapiSettings;
+
+const DEFAULT_PROFILE_NAME: &str = "default";
+
+const DEFAULT_RETRIES: usize = 3;
+
+/// Milliseconds
+const DEFAULT_RETRY_BACKOFF: u64 = 500;
+
+/// Named set of default values for the command flags
+#[derive(Deserialize, Default, Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    pub network: Option<String>,
+    /// One or more DAPI endpoints, separated by comma
+    pub dapi_url: Option<String>,
+    pub core_rpc_url: Option<String>,
+    pub identity: Option<String>,
+    pub private_key: Option<String>,
+    /// Label of the key in the encrypted keystore
+    pub key: Option<String>,
+    /// Path to the file with BIP39 mnemonic to derive identity keys from
+    pub mnemonic: Option<String>,
+    pub pro_tx_hash: Option<String>,
+    pub voting_private_key: Option<String>,
+    pub voting_key: Option<String>,
+    pub owner_private_key: Option<String>,
+    pub owner_key: Option<String>,
+    /// Path to the encrypted keystore file
+    pub keystore: Option<String>,
+    /// Path to the file tracking nonces of pending state transitions
+    pub nonce_state: Option<String>,
+    /// Connect timeout for DAPI requests, in seconds
+    pub connect_timeout: Option<u64>,
+    /// Request timeout for DAPI requests, in seconds
+    pub request_timeout: Option<u64>,
+    /// How many times a failed DAPI request is repeated
+    pub retries: Option<usize>,
+    /// Delay before the first retry of a DAPI request in milliseconds, doubled on every next one
+    pub retry_backoff: Option<u64>,
+    /// Ban DAPI endpoints that failed a request for a while
+    pub ban_failed_address: Option<bool>,
+}
+
+#[derive(Deserialize, Default, Debug)]
+pub struct Config {
+    #[serde(default)]
+    pub profiles: HashMap<String, Profile>,
+}
+
+impl Config {
+    /// $XDG_CONFIG_HOME/platform-cli, or ~/.config/platform-cli
+    pub fn default_dir() -> Option<PathBuf> {
+        let config_dir = env::var("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|_| env::var("HOME").map(|home| PathBuf::from(home).join(".config")))
+            .ok()?;
+
+        Some(config_dir.join("platform-cli"))
+    }
+
+    pub fn default_path() -> Option<PathBuf> {
+        Config::default_dir().map(|dir| dir.join("config.toml"))
+    }
+
+    pub fn load(path: Option<&str>) -> Result<Config, Error> {
+        let config_path = match path {
+            Some(path) => PathBuf::from(path),
+            None => match Config::default_path() {
+                Some(path) if path.exists() => path,
+                _ => return Ok(Config::default())
+            }
+        };
+
+        let data = fs::read_to_string(&config_path)
+            .map_err(|err| Error::ConfigFileError(ConfigFileError::from(format!("could not read {}: {}", config_path.display(), err).as_str())))?;
+
+        toml::from_str(&data)
+            .map_err(|err| Error::ConfigFileError(ConfigFileError::from(format!("could not parse {}: {}", config_path.display(), err).as_str())))
+    }
+
+    /// Returns the requested profile, or the `default` one (if present) when none was requested
+    pub fn profile(&self, name: Option<&str>) -> Result<Profile, Error> {
+        match name {
+            Some(name) => self.profiles
+                .get(name)
+                .cloned()
+                .ok_or(Error::ConfigFileError(ConfigFileError::from(format!("profile {} not found", name).as_str()))),
+            None => Ok(self.profiles.get(DEFAULT_PROFILE_NAME).cloned().unwrap_or_default())
+        }
+    }
+}
+
+impl Profile {
+    /// Fills the flag value from the profile, if it was not passed in command line or environment
+    pub fn fill(value: &mut String, profile_value: &Option<String>) {
+        if value.is_empty() {
+            if let Some(profile_value) = profile_value {
+                *value = profile_value.clone();
+            }
+        }
+    }
+
+    /// DAPI settings from the global flags, falling back to the profile values
+    pub fn dapi_settings(&self, flags: &GlobalFlags) -> DapiSettings {
+        DapiSettings {
+            request_settings: RequestSettings {
+                connect_timeout: flags.connect_timeout.or(self.connect_timeout).map(Duration::from_secs),
+                timeout: flags.request_timeout.or(self.request_timeout).map(Duration::from_secs),
+                // retries with backoff are done by PlatformGRPCClient itself
+                retries: Some(0),
+                ban_failed_address: flags.ban_failed_address.or(self.ban_failed_address),
+                ..RequestSettings::default()
+            },
+            retries: flags.retries.or(self.retries).unwrap_or(DEFAULT_RETRIES),
+            retry_backoff: Duration::from_millis(flags.retry_backoff.or(self.retry_backoff).unwrap_or(DEFAULT_RETRY_BACKOFF)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::output::OutputFormat;
+    use super::*;
+
+    const CONFIG: &str = r#"
+[profiles.default]
+network = "testnet"
+dapi_url = "https://127.0.0.1:1443"
+retries = 5
+
+[profiles.mainnet]
+network = "mainnet"
+"#;
+
+    fn flags() -> GlobalFlags {
+        GlobalFlags {
+            profile: None,
+            config: None,
+            keystore: None,
+            nonce_state: None,
+            output: OutputFormat::Text,
+            connect_timeout: None,
+            request_timeout: None,
+            retries: None,
+            retry_backoff: None,
+            ban_failed_address: None,
+        }
+    }
+
+    #[test]
+    fn fill_keeps_command_line_value() {
+        let mut value = String::from("mainnet");
+
+        Profile::fill(&mut value, &Some(String::from("testnet")));
+
+        assert_eq!(value, "mainnet");
+    }
+
+    #[test]
+    fn fill_takes_profile_value_for_omitted_flag() {
+        let mut value = String::new();
+
+        Profile::fill(&mut value, &Some(String::from("testnet")));
+        assert_eq!(value, "testnet");
+
+        let mut value = String::new();
+
+        Profile::fill(&mut value, &None);
+        assert_eq!(value, "");
+    }
+
+    #[test]
+    fn selects_requested_or_default_profile() {
+        let config: Config = toml::from_str(CONFIG).unwrap();
+
+        assert_eq!(config.profile(None).ok().unwrap().network.as_deref(), Some("testnet"));
+        assert_eq!(config.profile(Some("mainnet")).ok().unwrap().network.as_deref(), Some("mainnet"));
+        assert!(config.profile(Some("devnet")).is_err());
+        assert!(Config::default().profile(None).ok().unwrap().network.is_none());
+    }
+
+    #[test]
+    fn rejects_unknown_profile_fields() {
+        assert!(toml::from_str::<Config>("[profiles.default]\nnetwrok = \"testnet\"").is_err());
+    }
+
+    #[test]
+    fn dapi_settings_prefer_flags_over_profile() {
+        let config: Config = toml::from_str(CONFIG).unwrap();
+        let profile = config.profile(None).ok().unwrap();
+
+        let settings = profile.dapi_settings(&flags());
+        assert_eq!(settings.retries, 5);
+        assert_eq!(settings.retry_backoff, Duration::from_millis(DEFAULT_RETRY_BACKOFF));
+
+        let settings = profile.dapi_settings(&GlobalFlags { retries: Some(1), ..flags() });
+        assert_eq!(settings.retries, 1);
+
+        let settings = Profile::default().dapi_settings(&flags());
+        assert_eq!(settings.retries, DEFAULT_RETRIES);
+    }
+}
@@ -0,0 +1,41 @@
+use std::path::PathBuf;
+use crate::config::{Config, Profile};
+use crate::errors::Error;
+use crate::flags::GlobalFlags;
+use crate::grpc::DapiSettings;
+use crate::nonce_manager::NonceManager;
+
+/// State shared by all commands, resolved from global flags and the config file
+pub struct Context {
+    pub profile: Profile,
+    pub dapi_settings: DapiSettings,
+    pub keystore_path: PathBuf,
+    pub nonce_manager: NonceManager,
+}
+
+impl Context {
+    pub fn new(flags: &GlobalFlags) -> Result<Context, Error> {
+        let config = Config::load(flags.config.as_deref())?;
+        let profile = config.profile(flags.profile.as_deref())?;
+        let dapi_settings = profile.dapi_settings(flags);
+
+        let keystore_path = flags.keystore.clone()
+            .or(profile.keystore.clone())
+            .map(PathBuf::from)
+            .or(Config::default_dir().map(|dir| dir.join("keystore.json")))
+            .unwrap_or(PathBuf::from("keystore.json"));
+
+        let nonce_state_path = flags.nonce_state.clone()
+            .or(profile.nonce_state.clone())
+            .map(PathBuf::from)
+            .or(Config::default_dir().map(|dir| dir.join("nonces.json")))
+            .unwrap_or(PathBuf::from("nonces.json"));
+
+        Ok(Context {
+            profile,
+            dapi_settings,
+            keystore_path,
+            nonce_manager: NonceManager::new(&nonce_state_path),
+        })
+    }
+}
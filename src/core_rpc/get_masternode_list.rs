@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+use serde::Deserialize;
+use serde_json::Value;
+use crate::core_rpc::CoreRPCClient;
+use crate::errors::Error;
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MasternodeListEntry {
+    pub pro_tx_hash: String,
+    pub address: String,
+    pub status: String,
+    #[serde(rename = "type")]
+    pub masternode_type: String,
+    #[serde(rename = "platformHTTPPort")]
+    pub platform_http_port: Option<u16>,
+}
+
+impl MasternodeListEntry {
+    pub fn is_enabled_evonode(&self) -> bool {
+        self.status == "ENABLED" && self.masternode_type == "Evo" && self.platform_http_port.is_some()
+    }
+
+    /// DAPI endpoint of the evonode, built from its Core P2P address host and platform HTTP port
+    pub fn dapi_url(&self) -> Option<String> {
+        let (host, _) = self.address.rsplit_once(':')?;
+        let port = self.platform_http_port?;
+
+        Some(format!("https://{}:{}", host, port))
+    }
+}
+
+impl CoreRPCClient {
+    pub async fn get_masternode_list(&self) -> Result<Vec<MasternodeListEntry>, Error> {
+        let masternodes: HashMap<String, MasternodeListEntry> = self
+            .call("masternodelist", vec![Value::from("json")])
+            .await?;
+
+        Ok(masternodes.into_values().collect())
+    }
+}
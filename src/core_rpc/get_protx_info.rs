@@ -0,0 +1,27 @@
+use serde::Deserialize;
+use serde_json::Value;
+use crate::core_rpc::CoreRPCClient;
+use crate::errors::Error;
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProTxState {
+    pub owner_address: String,
+    pub voting_address: String,
+    pub payout_address: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProTxInfo {
+    pub pro_tx_hash: String,
+    #[serde(rename = "type")]
+    pub masternode_type: String,
+    pub state: ProTxState,
+}
+
+impl CoreRPCClient {
+    pub async fn get_protx_info(&self, pro_tx_hash: &str) -> Result<ProTxInfo, Error> {
+        self.call("protx", vec![Value::from("info"), Value::from(pro_tx_hash)]).await
+    }
+}
@@ -0,0 +1,75 @@
+use dpp::consensus::basic::BasicError;
+use dpp::consensus::ConsensusError;
+use dpp::data_contract::DataContract;
+use dpp::platform_value::Value;
+use dpp::version::PlatformVersion;
+use crate::errors::document_validation_error::DocumentValidationError;
+use crate::errors::Error;
+
+/// Checks document properties against the JSON schema of the document type with the platform validator before they are signed,
+/// so invalid documents are not paid for and rejected by the network
+pub struct DocumentValidator;
+
+impl DocumentValidator {
+    pub fn validate(data_contract: &DataContract, document_type_name: &str, properties: &Value) -> Result<(), Error> {
+        let violations = DocumentValidator::violations(data_contract, document_type_name, properties)?;
+
+        if !violations.is_empty() {
+            return Err(Error::DocumentValidationError(DocumentValidationError::from(violations)));
+        }
+
+        Ok(())
+    }
+
+    /// Returns all schema violations of the properties, each prefixed with JSON path of the property, ex. $.records.identity
+    pub fn violations(data_contract: &DataContract, document_type_name: &str, properties: &Value) -> Result<Vec<String>, Error> {
+        // platform validates the same way only the document data, system properties like $createdAt are checked separately
+        let result = data_contract.validate_document_properties(document_type_name, properties.clone(), PlatformVersion::latest())?;
+
+        Ok(result.errors
+            .iter()
+            .map(DocumentValidator::violation)
+            .collect())
+    }
+
+    fn violation(error: &ConsensusError) -> String {
+        match error {
+            ConsensusError::BasicError(BasicError::JsonSchemaError(error)) => format!("{}: {}", DocumentValidator::json_path(error.instance_path()), error.error_summary()),
+            _ => format!("$: {}", error),
+        }
+    }
+
+    /// Converts JSON pointer of the JSON schema error to JSON path, ex. /records/identity to $.records.identity
+    fn json_path(instance_path: &str) -> String {
+        instance_path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+            .fold(String::from("$"), |path, segment| match segment.parse::<usize>() {
+                Ok(index) => format!("{}[{}]", path, index),
+                Err(_) => format!("{}.{}", path, segment),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_path_of_root() {
+        assert_eq!(DocumentValidator::json_path(""), "$");
+        assert_eq!(DocumentValidator::json_path("/"), "$");
+    }
+
+    #[test]
+    fn json_path_of_nested_properties() {
+        assert_eq!(DocumentValidator::json_path("/records/identity"), "$.records.identity");
+        assert_eq!(DocumentValidator::json_path("/items/1/name"), "$.items[1].name");
+    }
+
+    #[test]
+    fn json_path_unescapes_pointer() {
+        assert_eq!(DocumentValidator::json_path("/a~1b/c~0d"), "$.a/b.c~d");
+    }
+}
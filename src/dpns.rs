@@ -0,0 +1,228 @@
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::conversion::value::v0::DataContractValueConversionMethodsV0;
+use dpp::data_contract::DataContract;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::identifier::Identifier;
+use dpp::platform_value::{platform_value, Value};
+use dpp::platform_value::string_encoding::Encoding::Base58;
+use dpp::util::strings::convert_to_homograph_safe_chars;
+use dpp::version::PlatformVersion;
+use dpp::voting::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePoll;
+use dpp::voting::vote_polls::VotePoll;
+use log::debug;
+use regex::Regex;
+use crate::constants::Constants;
+use crate::errors::cli_argument_invalid_input::CommandLineArgumentInvalidInput;
+use crate::errors::dpns_name_error::DpnsNameError;
+use crate::errors::Error;
+use crate::grpc::PlatformGRPCClient;
+
+/// Normalized labels matching this pattern are given out through a masternode vote
+const CONTESTED_LABEL_PATTERN: &str = r"^[a-zA-Z01-]{3,19}$";
+
+/// DPNS name with a contested poll that is not resolved yet
+pub struct ContestedName {
+    pub vote_poll: ContestedDocumentResourceVotePoll,
+    pub normalized_label: String,
+    pub normalized_parent_domain_name: String,
+    /// Unix time in milliseconds
+    pub end_time_ms: u64,
+}
+
+pub struct Dpns;
+
+impl Dpns {
+    pub fn data_contract() -> DataContract {
+        DataContract::from_value(Constants::dpns_data_contract_value(), true, PlatformVersion::latest()).unwrap()
+    }
+
+    /// Splits full domain name into label and parent domain name, names without a parent are considered top level .dash names.
+    /// The contract limits parentDomainName to a single label, so deeper names like service.ourorg.dash are rejected
+    pub fn split_name(name: &str) -> Result<(String, String), Error> {
+        match name.split('.').collect::<Vec<&str>>().as_slice() {
+            [label] => Ok((String::from(*label), String::from("dash"))),
+            [label, parent_domain_name] => Ok((String::from(*label), String::from(*parent_domain_name))),
+            _ => Err(Error::CommandLineArgumentInvalidInput(CommandLineArgumentInvalidInput::from(format!("DPNS name {} is not supported, the DPNS contract accepts only names directly under a top level domain, ex. alice.dash", name).as_str()))),
+        }
+    }
+
+    /// Fetches the domain document of the name, compared by the normalized label and parent domain name
+    pub async fn find_domain(platform_grpc_client: &PlatformGRPCClient, label: &str, parent_domain_name: &str) -> Result<Option<Document>, Error> {
+        let where_clauses = platform_value!([
+            ["normalizedParentDomainName", "==", convert_to_homograph_safe_chars(parent_domain_name)],
+            ["normalizedLabel", "==", convert_to_homograph_safe_chars(label)],
+        ]);
+
+        let domains = platform_grpc_client
+            .get_documents(&Dpns::data_contract(), "domain", where_clauses, platform_value!([]), 1).await?;
+
+        Ok(domains.into_iter().next())
+    }
+
+    /// Resolves identifier of the identity from the DPNS name records, ex. alice.dash
+    pub async fn resolve_name(platform_grpc_client: &PlatformGRPCClient, name: &str) -> Result<Identifier, Error> {
+        let (label, parent_domain_name) = Dpns::split_name(name)?;
+
+        let domain = Dpns::find_domain(platform_grpc_client, &label, &parent_domain_name).await?
+            .ok_or(Error::CommandLineArgumentInvalidInput(CommandLineArgumentInvalidInput::from(format!("DPNS name {}.{} is not registered", label, parent_domain_name).as_str())))?;
+
+        let identifier = domain.get("records.identity")
+            .and_then(|value| value.to_identifier().ok())
+            .ok_or(Error::CommandLineArgumentInvalidInput(CommandLineArgumentInvalidInput::from(format!("DPNS name {}.{} has no identity record", label, parent_domain_name).as_str())))?;
+
+        debug!("DPNS name {}.{} resolved to identity {}", label, parent_domain_name, identifier.to_string(Base58));
+
+        Ok(identifier)
+    }
+
+    /// Resolves identity given either by identifier or DPNS name
+    pub async fn resolve_identity(platform_grpc_client: &PlatformGRPCClient, identity_or_name: &str) -> Result<Identifier, Error> {
+        if !identity_or_name.contains('.') {
+            if let Ok(identifier) = Identifier::from_string(identity_or_name, Base58) {
+                return Ok(identifier);
+            }
+        }
+
+        Dpns::resolve_name(platform_grpc_client, identity_or_name).await
+    }
+
+    /// Checks the label against the DPNS contract rules, so invalid names are rejected before any credits are spent
+    pub fn validate_label(label: &str) -> Result<(), Error> {
+        let invalid = |reason: &str| Error::CommandLineArgumentInvalidInput(CommandLineArgumentInvalidInput::from(format!("DPNS label {} {}", label, reason).as_str()));
+
+        let length = label.chars().count();
+
+        if !(3..=63).contains(&length) {
+            return Err(invalid(format!("must be 3 to 63 characters long, got {}", length).as_str()));
+        }
+
+        if let Some(character) = label.chars().find(|character| !character.is_ascii_alphanumeric() && *character != '-') {
+            return Err(invalid(format!("contains {:?}, only letters a-z, A-Z, digits and hyphens are allowed", character).as_str()));
+        }
+
+        if label.starts_with('-') || label.ends_with('-') {
+            return Err(invalid("must not start or end with a hyphen"));
+        }
+
+        if label.contains("--") {
+            return Err(invalid("must not contain double hyphens"));
+        }
+
+        Ok(())
+    }
+
+    /// Whether registration of the normalized label starts a masternode vote
+    pub fn is_contested(normalized_label: &str) -> bool {
+        Regex::new(CONTESTED_LABEL_PATTERN).unwrap().is_match(normalized_label)
+    }
+
+    /// Fails if the name is already registered, locked or has an ongoing contested poll.
+    /// Preorders only contain salted hashes of the names, so they can not be matched against the label
+    pub async fn check_availability(platform_grpc_client: &PlatformGRPCClient, label: &str, parent_domain_name: &str) -> Result<(), Error> {
+        let normalized_label = convert_to_homograph_safe_chars(label);
+        let normalized_parent_domain_name = convert_to_homograph_safe_chars(parent_domain_name);
+        let full_domain_name = format!("{}.{}", label, parent_domain_name);
+
+        if let Some(domain) = Dpns::find_domain(platform_grpc_client, label, parent_domain_name).await? {
+            return Err(Error::DpnsNameError(DpnsNameError::from(format!("{} is already registered to identity {}", full_domain_name, domain.owner_id().to_string(Base58)).as_str())));
+        }
+
+        if !Dpns::is_contested(&normalized_label) {
+            return Ok(());
+        }
+
+        let contest = platform_grpc_client.get_contested_resource_vote_state(
+            Dpns::data_contract().id(),
+            "domain",
+            "parentNameAndLabel",
+            vec![Value::Text(normalized_parent_domain_name), Value::Text(normalized_label)],
+        ).await?;
+
+        if contest.finished_vote_info.is_some() {
+            return Err(Error::DpnsNameError(DpnsNameError::from(format!("contested poll on {} has already finished, the name is locked or awarded", full_domain_name).as_str())));
+        }
+
+        if !contest.contenders.is_empty() {
+            return Err(Error::DpnsNameError(DpnsNameError::from(format!("{} is currently contested by {} identities", full_domain_name, contest.contenders.len()).as_str())));
+        }
+
+        debug!("DPNS name {} is available", full_domain_name);
+
+        Ok(())
+    }
+
+    /// Lists DPNS names with contested polls awaiting resolution
+    pub async fn contested_names(platform_grpc_client: &PlatformGRPCClient) -> Result<Vec<ContestedName>, Error> {
+        let dpns_contract_id = Dpns::data_contract().id();
+
+        let vote_polls = platform_grpc_client.get_vote_polls_by_end_date().await?;
+
+        let contested_names = vote_polls
+            .into_iter()
+            .filter_map(|(end_time_ms, vote_poll)| match vote_poll {
+                VotePoll::ContestedDocumentResourceVotePoll(vote_poll) => Some((end_time_ms, vote_poll)),
+            })
+            .filter(|(_, vote_poll)| vote_poll.contract_id == dpns_contract_id && vote_poll.document_type_name == "domain" && vote_poll.index_name == "parentNameAndLabel")
+            .filter_map(|(end_time_ms, vote_poll)| {
+                match vote_poll.index_values.as_slice() {
+                    [Value::Text(parent_domain_name), Value::Text(label)] => Some(ContestedName {
+                        normalized_label: label.clone(),
+                        normalized_parent_domain_name: parent_domain_name.clone(),
+                        end_time_ms,
+                        vote_poll,
+                    }),
+                    _ => None,
+                }
+            })
+            .collect();
+
+        Ok(contested_names)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_names_under_top_level_domain() {
+        assert_eq!(Dpns::split_name("alice").ok(), Some((String::from("alice"), String::from("dash"))));
+        assert_eq!(Dpns::split_name("alice.dash").ok(), Some((String::from("alice"), String::from("dash"))));
+    }
+
+    #[test]
+    fn rejects_deeper_names() {
+        assert!(Dpns::split_name("service.ourorg.dash").is_err());
+        assert!(Dpns::split_name("a.b.c.dash").is_err());
+    }
+
+    #[test]
+    fn validates_labels() {
+        assert!(Dpns::validate_label("alice").is_ok());
+        assert!(Dpns::validate_label("Alice-2024").is_ok());
+        assert!(Dpns::validate_label(&"a".repeat(63)).is_ok());
+
+        assert!(Dpns::validate_label("al").is_err());
+        assert!(Dpns::validate_label(&"a".repeat(64)).is_err());
+        assert!(Dpns::validate_label("ali_ce").is_err());
+        assert!(Dpns::validate_label("-alice").is_err());
+        assert!(Dpns::validate_label("alice-").is_err());
+        assert!(Dpns::validate_label("ali--ce").is_err());
+        assert!(Dpns::validate_label("alíce").is_err());
+    }
+
+    #[test]
+    fn normalizes_homograph_characters() {
+        assert_eq!(convert_to_homograph_safe_chars("Alice"), "a11ce");
+        assert_eq!(convert_to_homograph_safe_chars("BOB"), "b0b");
+    }
+
+    #[test]
+    fn detects_contested_labels() {
+        assert!(Dpns::is_contested(&convert_to_homograph_safe_chars("alice")));
+        assert!(Dpns::is_contested("b0b"));
+        assert!(!Dpns::is_contested("al"));
+        assert!(!Dpns::is_contested("alice2"));
+        assert!(!Dpns::is_contested(&"a".repeat(20)));
+    }
+}
@@ -0,0 +1,16 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub struct BroadcastError(String);
+
+impl From<&str> for BroadcastError {
+    fn from(value: &str) -> Self {
+        BroadcastError(String::from(value))
+    }
+}
+
+impl fmt::Display for BroadcastError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "State transition was rejected: {}", &self.0)
+    }
+}
@@ -0,0 +1,16 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub struct ConfigFileError(String);
+
+impl From<&str> for ConfigFileError {
+    fn from(value: &str) -> Self {
+        ConfigFileError(String::from(value))
+    }
+}
+
+impl fmt::Display for ConfigFileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Config file error: {}", &self.0)
+    }
+}
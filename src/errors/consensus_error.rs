@@ -0,0 +1,35 @@
+use std::fmt;
+use dpp::consensus::codes::ErrorWithCode;
+
+/// State transition was rejected by the platform consensus rules
+#[derive(Debug)]
+pub struct ConsensusError {
+    code: u32,
+    /// Validation stage that rejected the transition: basic, signature, state or fee
+    class: String,
+    message: String,
+}
+
+impl From<dpp::consensus::ConsensusError> for ConsensusError {
+    fn from(value: dpp::consensus::ConsensusError) -> Self {
+        let class = match &value {
+            dpp::consensus::ConsensusError::BasicError(_) => "basic",
+            dpp::consensus::ConsensusError::SignatureError(_) => "signature",
+            dpp::consensus::ConsensusError::StateError(_) => "state",
+            dpp::consensus::ConsensusError::FeeError(_) => "fee",
+            _ => "unknown",
+        };
+
+        ConsensusError {
+            code: value.code(),
+            class: String::from(class),
+            message: value.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ConsensusError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Consensus error {} ({}): {}", self.code, &self.class, &self.message)
+    }
+}
@@ -0,0 +1,16 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub struct CoreRPCResponseError(String);
+
+impl From<&str> for CoreRPCResponseError {
+    fn from(value: &str) -> Self {
+        CoreRPCResponseError(String::from(value))
+    }
+}
+
+impl fmt::Display for CoreRPCResponseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Core RPC error: {}", &self.0)
+    }
+}
@@ -0,0 +1,19 @@
+use std::fmt;
+
+/// Document properties do not match the schema of the document type
+#[derive(Debug)]
+pub struct DocumentValidationError {
+    violations: Vec<String>,
+}
+
+impl From<Vec<String>> for DocumentValidationError {
+    fn from(violations: Vec<String>) -> Self {
+        DocumentValidationError { violations }
+    }
+}
+
+impl fmt::Display for DocumentValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Document validation failed: {}", self.violations.join("; "))
+    }
+}
@@ -0,0 +1,17 @@
+use std::fmt;
+
+/// DPNS name can not be registered or managed, ex. it is already taken or contested
+#[derive(Debug)]
+pub struct DpnsNameError(String);
+
+impl From<&str> for DpnsNameError {
+    fn from(value: &str) -> Self {
+        DpnsNameError(String::from(value))
+    }
+}
+
+impl fmt::Display for DpnsNameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DPNS name error: {}", &self.0)
+    }
+}
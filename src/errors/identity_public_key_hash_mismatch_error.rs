@@ -7,14 +7,17 @@ use dpp::platform_value::string_encoding::Encoding::Base58;
 #[derive(Debug)]
 pub struct IdentifierAndPublicKeyHash {
     identifier: Identifier,
-    pub_key_hash: PubkeyHash
+    pub_key_hashes: Vec<PubkeyHash>
 }
 #[derive(Debug)]
 pub struct IdentityPublicKeyHashMismatchError(IdentifierAndPublicKeyHash);
 
 impl fmt::Display for IdentityPublicKeyHashMismatchError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "Could not find public key {} in the identity {}", self.0.pub_key_hash.to_hex(), self.0.identifier.to_string(Base58))
+        match self.0.pub_key_hashes.as_slice() {
+            [pub_key_hash] => write!(f, "Could not find public key {} in the identity {}", pub_key_hash.to_hex(), self.0.identifier.to_string(Base58)),
+            pub_key_hashes => write!(f, "Could not find any of {} public keys in the identity {}", pub_key_hashes.len(), self.0.identifier.to_string(Base58)),
+        }
     }
 }
 
@@ -22,6 +25,13 @@ impl fmt::Display for IdentityPublicKeyHashMismatchError {
 impl From<(Identifier, PubkeyHash)> for IdentityPublicKeyHashMismatchError {
     fn from(value: (Identifier, PubkeyHash)) -> Self {
         let (identifier, pub_key_hash) = value;
-        return IdentityPublicKeyHashMismatchError(IdentifierAndPublicKeyHash{ identifier, pub_key_hash });
+        return IdentityPublicKeyHashMismatchError(IdentifierAndPublicKeyHash{ identifier, pub_key_hashes: vec![pub_key_hash] });
+    }
+}
+
+impl From<(Identifier, Vec<PubkeyHash>)> for IdentityPublicKeyHashMismatchError {
+    fn from(value: (Identifier, Vec<PubkeyHash>)) -> Self {
+        let (identifier, pub_key_hashes) = value;
+        return IdentityPublicKeyHashMismatchError(IdentifierAndPublicKeyHash{ identifier, pub_key_hashes });
     }
 }
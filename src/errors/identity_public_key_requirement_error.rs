@@ -0,0 +1,16 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub struct IdentityPublicKeyRequirementError(String);
+
+impl From<&str> for IdentityPublicKeyRequirementError {
+    fn from(value: &str) -> Self {
+        IdentityPublicKeyRequirementError(String::from(value))
+    }
+}
+
+impl fmt::Display for IdentityPublicKeyRequirementError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", &self.0)
+    }
+}
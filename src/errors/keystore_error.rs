@@ -0,0 +1,16 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub struct KeystoreError(String);
+
+impl From<&str> for KeystoreError {
+    fn from(value: &str) -> Self {
+        KeystoreError(String::from(value))
+    }
+}
+
+impl fmt::Display for KeystoreError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Keystore error: {}", &self.0)
+    }
+}
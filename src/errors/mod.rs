@@ -1,23 +1,58 @@
 use std::fmt::{Display, Formatter};
 use crate::errors::cli_argument_invalid_input::CommandLineArgumentInvalidInput;
 use crate::errors::cli_argument_missing_error::CommandLineArgumentMissingError;
+use crate::errors::config_file_error::ConfigFileError;
+use crate::errors::core_rpc_response_error::CoreRPCResponseError;
 use crate::errors::dapi_response_error::DapiResponseError;
 use crate::errors::identity_not_found_error::{IdentityNotFoundError};
 use crate::errors::identity_public_key_hash_mismatch_error::IdentityPublicKeyHashMismatchError;
+use crate::errors::identity_public_key_requirement_error::IdentityPublicKeyRequirementError;
+use crate::errors::keystore_error::KeystoreError;
+use crate::errors::withdrawal_status_error::WithdrawalStatusError;
+use crate::errors::state_transition_error::StateTransitionError;
+use crate::errors::broadcast_error::BroadcastError;
+use crate::errors::consensus_error::ConsensusError;
+use crate::errors::nonce_error::NonceError;
+use crate::errors::document_validation_error::DocumentValidationError;
+use crate::errors::dpns_name_error::DpnsNameError;
+use dpp::ProtocolError;
 
 pub mod cli_argument_missing_error;
 pub mod identity_not_found_error;
 pub mod dapi_response_error;
 pub mod identity_public_key_hash_mismatch_error;
 pub mod cli_argument_invalid_input;
+pub mod core_rpc_response_error;
+pub mod config_file_error;
+pub mod keystore_error;
+pub mod identity_public_key_requirement_error;
+pub mod withdrawal_status_error;
+pub mod state_transition_error;
+pub mod broadcast_error;
+pub mod consensus_error;
+pub mod nonce_error;
+pub mod document_validation_error;
+pub mod dpns_name_error;
 
 
+#[derive(Debug)]
 pub enum Error {
     CommandLineArgumentMissingError(CommandLineArgumentMissingError),
     CommandLineArgumentInvalidInput(CommandLineArgumentInvalidInput),
     IdentityNotFoundError(IdentityNotFoundError),
     IdentityPublicKeyHashMismatchError(IdentityPublicKeyHashMismatchError),
     DapiResponseError(DapiResponseError),
+    CoreRPCResponseError(CoreRPCResponseError),
+    ConfigFileError(ConfigFileError),
+    KeystoreError(KeystoreError),
+    IdentityPublicKeyRequirementError(IdentityPublicKeyRequirementError),
+    WithdrawalStatusError(WithdrawalStatusError),
+    StateTransitionError(StateTransitionError),
+    BroadcastError(BroadcastError),
+    ConsensusError(ConsensusError),
+    NonceError(NonceError),
+    DocumentValidationError(DocumentValidationError),
+    DpnsNameError(DpnsNameError),
 }
 
 impl Display for Error {
@@ -38,7 +73,141 @@ impl Display for Error {
             Error::CommandLineArgumentInvalidInput(err) => {
                 write!(f, "{}", err)
             }
+            Error::CoreRPCResponseError(err) => {
+                write!(f, "{}", err)
+            }
+            Error::ConfigFileError(err) => {
+                write!(f, "{}", err)
+            }
+            Error::KeystoreError(err) => {
+                write!(f, "{}", err)
+            }
+            Error::IdentityPublicKeyRequirementError(err) => {
+                write!(f, "{}", err)
+            }
+            Error::WithdrawalStatusError(err) => {
+                write!(f, "{}", err)
+            }
+            Error::StateTransitionError(err) => {
+                write!(f, "{}", err)
+            }
+            Error::BroadcastError(err) => {
+                write!(f, "{}", err)
+            }
+            Error::ConsensusError(err) => {
+                write!(f, "{}", err)
+            }
+            Error::NonceError(err) => {
+                write!(f, "{}", err)
+            }
+            Error::DocumentValidationError(err) => {
+                write!(f, "{}", err)
+            }
+            Error::DpnsNameError(err) => {
+                write!(f, "{}", err)
+            }
         }
     }
 }
 
+impl Error {
+    /// Stable machine-readable code of the error, used in JSON output
+    pub fn code(&self) -> &'static str {
+        match self {
+            Error::CommandLineArgumentMissingError(_) => "command_line_argument_missing",
+            Error::CommandLineArgumentInvalidInput(_) => "command_line_argument_invalid",
+            Error::IdentityNotFoundError(_) => "identity_not_found",
+            Error::IdentityPublicKeyHashMismatchError(_) => "identity_public_key_hash_mismatch",
+            Error::DapiResponseError(_) => "dapi_response_error",
+            Error::CoreRPCResponseError(_) => "core_rpc_response_error",
+            Error::ConfigFileError(_) => "config_file_error",
+            Error::KeystoreError(_) => "keystore_error",
+            Error::IdentityPublicKeyRequirementError(_) => "identity_public_key_requirement",
+            Error::WithdrawalStatusError(_) => "withdrawal_status_error",
+            Error::StateTransitionError(_) => "state_transition_error",
+            Error::BroadcastError(_) => "broadcast_rejected",
+            Error::ConsensusError(_) => "consensus_error",
+            Error::NonceError(_) => "nonce_error",
+            Error::DocumentValidationError(_) => "document_validation_error",
+            Error::DpnsNameError(_) => "dpns_name_error",
+        }
+    }
+
+    /// Process exit code of the error, documented in README, must not change between releases.
+    /// Code 2 is left to clap, which exits with it on command line usage errors
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::CommandLineArgumentInvalidInput(_) => 3,
+            Error::ConfigFileError(_) => 4,
+            Error::KeystoreError(_) => 5,
+            Error::NonceError(_) => 6,
+            Error::CommandLineArgumentMissingError(_) => 7,
+            Error::IdentityNotFoundError(_) => 10,
+            Error::IdentityPublicKeyHashMismatchError(_) => 11,
+            Error::IdentityPublicKeyRequirementError(_) => 12,
+            Error::DapiResponseError(_) => 20,
+            Error::CoreRPCResponseError(_) => 21,
+            Error::StateTransitionError(_) => 30,
+            Error::BroadcastError(_) => 31,
+            Error::ConsensusError(_) => 32,
+            Error::DocumentValidationError(_) => 33,
+            Error::WithdrawalStatusError(_) => 40,
+            Error::DpnsNameError(_) => 50,
+        }
+    }
+}
+
+impl From<ProtocolError> for Error {
+    fn from(err: ProtocolError) -> Self {
+        Error::StateTransitionError(StateTransitionError::from(err.to_string().as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all_errors() -> Vec<Error> {
+        vec![
+            Error::CommandLineArgumentMissingError(CommandLineArgumentMissingError::from("network")),
+            Error::CommandLineArgumentInvalidInput(CommandLineArgumentInvalidInput::from("")),
+            Error::IdentityNotFoundError(IdentityNotFoundError::from(dpp::identifier::Identifier::default())),
+            Error::IdentityPublicKeyHashMismatchError(IdentityPublicKeyHashMismatchError::from((dpp::identifier::Identifier::default(), vec![]))),
+            Error::DapiResponseError(DapiResponseError::from("")),
+            Error::CoreRPCResponseError(CoreRPCResponseError::from("")),
+            Error::ConfigFileError(ConfigFileError::from("")),
+            Error::KeystoreError(KeystoreError::from("")),
+            Error::IdentityPublicKeyRequirementError(IdentityPublicKeyRequirementError::from("")),
+            Error::WithdrawalStatusError(WithdrawalStatusError::from("")),
+            Error::StateTransitionError(StateTransitionError::from("")),
+            Error::BroadcastError(BroadcastError::from("")),
+            Error::ConsensusError(ConsensusError::from(dpp::consensus::ConsensusError::DefaultError)),
+            Error::NonceError(NonceError::from("")),
+            Error::DocumentValidationError(DocumentValidationError::from(vec![])),
+            Error::DpnsNameError(DpnsNameError::from("")),
+        ]
+    }
+
+    #[test]
+    fn exit_codes_are_unique() {
+        let mut exit_codes: Vec<i32> = all_errors().iter().map(Error::exit_code).collect();
+        exit_codes.sort();
+        exit_codes.dedup();
+
+        assert_eq!(exit_codes.len(), all_errors().len());
+    }
+
+    #[test]
+    fn exit_codes_do_not_collide_with_clap() {
+        for err in all_errors() {
+            assert!(![0, 1, 2].contains(&err.exit_code()), "{} exits with reserved code {}", err.code(), err.exit_code());
+        }
+    }
+
+    #[test]
+    fn exit_codes_are_stable() {
+        assert_eq!(Error::CommandLineArgumentMissingError(CommandLineArgumentMissingError::from("network")).exit_code(), 7);
+        assert_eq!(Error::CommandLineArgumentInvalidInput(CommandLineArgumentInvalidInput::from("")).exit_code(), 3);
+        assert_eq!(Error::DpnsNameError(DpnsNameError::from("")).exit_code(), 50);
+    }
+}
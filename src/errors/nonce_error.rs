@@ -0,0 +1,16 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub struct NonceError(String);
+
+impl From<&str> for NonceError {
+    fn from(value: &str) -> Self {
+        NonceError(String::from(value))
+    }
+}
+
+impl fmt::Display for NonceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Nonce error: {}", &self.0)
+    }
+}
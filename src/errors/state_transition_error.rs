@@ -0,0 +1,16 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub struct StateTransitionError(String);
+
+impl From<&str> for StateTransitionError {
+    fn from(value: &str) -> Self {
+        StateTransitionError(String::from(value))
+    }
+}
+
+impl fmt::Display for StateTransitionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "State transition error: {}", &self.0)
+    }
+}
@@ -0,0 +1,16 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub struct WithdrawalStatusError(String);
+
+impl From<&str> for WithdrawalStatusError {
+    fn from(value: &str) -> Self {
+        WithdrawalStatusError(String::from(value))
+    }
+}
+
+impl fmt::Display for WithdrawalStatusError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Withdrawal error: {}", &self.0)
+    }
+}
@@ -1,16 +1,24 @@
 use std::time::{SystemTime, UNIX_EPOCH};
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::DataContract;
 use dpp::document::{Document, DocumentV0, INITIAL_REVISION};
 use dpp::identifier::Identifier;
 use dpp::platform_value::{Value};
+use crate::errors::document_validation_error::DocumentValidationError;
+use crate::errors::Error;
 use crate::factories::Factories;
 
 impl Factories {
+    /// Creates a new document, its properties must be validated with DocumentValidator beforehand
     pub fn create_document(
-        data_contract_id: Identifier,
+        data_contract: &DataContract,
         document_type_name: &str,
         owner_id: Identifier,
         document_properties: Value,
-        entropy: Vec<u8>) -> Document {
+        entropy: Vec<u8>) -> Result<Document, Error> {
+        // fails for document types missing in the contract
+        data_contract.document_type_for_name(document_type_name)?;
+
         let now = SystemTime::now();
         let now_seconds = now
             .duration_since(UNIX_EPOCH)
@@ -18,7 +26,7 @@ impl Factories {
             .as_secs();
 
         let document_id = Document::generate_document_id_v0(
-            &data_contract_id,
+            &data_contract.id(),
             &owner_id,
             document_type_name,
             entropy.as_slice(),
@@ -26,7 +34,8 @@ impl Factories {
 
         let document: Document = Document::V0(DocumentV0 {
             id: document_id,
-            properties: document_properties.into_btree_string_map().unwrap(),
+            properties: document_properties.into_btree_string_map()
+                .map_err(|err| Error::DocumentValidationError(DocumentValidationError::from(vec![format!("$: {}", err)])))?,
             owner_id,
             revision: Some(INITIAL_REVISION),
             created_at: Some(now_seconds),
@@ -40,6 +49,6 @@ impl Factories {
             transferred_at_core_block_height: None,
         });
 
-        document
+        Ok(document)
     }
-}
\ No newline at end of file
+}
@@ -1,11 +1,16 @@
+use std::collections::BTreeMap;
 use dpp::document::{Document, DocumentV0Getters};
 use dpp::fee::Credits;
 use dpp::identifier::Identifier;
-use dpp::prelude::IdentityNonce;
-use dpp::state_transition::documents_batch_transition::{DocumentCreateTransition, DocumentsBatchTransition, DocumentsBatchTransitionV0};
+use dpp::platform_value::Value;
+use dpp::prelude::{IdentityNonce, Revision};
+use dpp::state_transition::documents_batch_transition::{DocumentCreateTransition, DocumentDeleteTransition, DocumentReplaceTransition, DocumentTransferTransition, DocumentsBatchTransition, DocumentsBatchTransitionV0};
 use dpp::state_transition::documents_batch_transition::document_base_transition::DocumentBaseTransition;
 use dpp::state_transition::documents_batch_transition::document_base_transition::v0::DocumentBaseTransitionV0;
 use dpp::state_transition::documents_batch_transition::document_create_transition::DocumentCreateTransitionV0;
+use dpp::state_transition::documents_batch_transition::document_delete_transition::DocumentDeleteTransitionV0;
+use dpp::state_transition::documents_batch_transition::document_replace_transition::DocumentReplaceTransitionV0;
+use dpp::state_transition::documents_batch_transition::document_transfer_transition::DocumentTransferTransitionV0;
 use dpp::state_transition::documents_batch_transition::document_transition::DocumentTransition;
 use dpp::state_transition::StateTransition;
 use crate::factories::Factories;
@@ -33,6 +38,57 @@ impl Factories {
 
         transition
     }
+
+    pub fn document_replace_transition(
+        document_id: Identifier,
+        document_type_name: &str,
+        data_contract_id: Identifier,
+        identity_contract_nonce: IdentityNonce,
+        revision: Revision,
+        data: BTreeMap<String, Value>) -> DocumentTransition {
+        DocumentTransition::Replace(DocumentReplaceTransition::V0(DocumentReplaceTransitionV0 {
+            base: Factories::document_base_transition(document_id, document_type_name, data_contract_id, identity_contract_nonce),
+            revision,
+            data,
+        }))
+    }
+
+    pub fn document_delete_transition(
+        document_id: Identifier,
+        document_type_name: &str,
+        data_contract_id: Identifier,
+        identity_contract_nonce: IdentityNonce) -> DocumentTransition {
+        DocumentTransition::Delete(DocumentDeleteTransition::V0(DocumentDeleteTransitionV0 {
+            base: Factories::document_base_transition(document_id, document_type_name, data_contract_id, identity_contract_nonce),
+        }))
+    }
+
+    pub fn document_transfer_transition(
+        document_id: Identifier,
+        document_type_name: &str,
+        data_contract_id: Identifier,
+        identity_contract_nonce: IdentityNonce,
+        revision: Revision,
+        recipient_owner_id: Identifier) -> DocumentTransition {
+        DocumentTransition::Transfer(DocumentTransferTransition::V0(DocumentTransferTransitionV0 {
+            base: Factories::document_base_transition(document_id, document_type_name, data_contract_id, identity_contract_nonce),
+            revision,
+            recipient_owner_id,
+        }))
+    }
+
+    fn document_base_transition(
+        document_id: Identifier,
+        document_type_name: &str,
+        data_contract_id: Identifier,
+        identity_contract_nonce: IdentityNonce) -> DocumentBaseTransition {
+        DocumentBaseTransition::V0(DocumentBaseTransitionV0 {
+            id: document_id,
+            identity_contract_nonce,
+            document_type_name: String::from(document_type_name),
+            data_contract_id,
+        })
+    }
 }
 
 pub struct IdentityStateTransition {
@@ -1,8 +1,5 @@
-use base64::Engine;
-use base64::engine::general_purpose;
 use dpp::identifier::Identifier;
 use dpp::platform_value::{Value};
-use dpp::platform_value::string_encoding::Encoding::Base64;
 use dpp::prelude::IdentityNonce;
 use dpp::state_transition::masternode_vote_transition::MasternodeVoteTransition;
 use dpp::state_transition::masternode_vote_transition::v0::MasternodeVoteTransitionV0;
@@ -12,6 +9,8 @@ use dpp::voting::vote_polls::VotePoll;
 use dpp::voting::votes::resource_vote::ResourceVote;
 use dpp::voting::votes::resource_vote::v0::ResourceVoteV0;
 use dpp::voting::votes::Vote;
+use crate::errors::cli_argument_invalid_input::CommandLineArgumentInvalidInput;
+use crate::errors::Error;
 use crate::factories::Factories;
 
 impl Factories {
@@ -22,10 +21,11 @@ impl Factories {
                                                    document_type_name: &str,
                                                    index_name: &str,
                                                    index_values: Vec<Value>,
-                                                   choice: ResourceVoteChoice) -> MasternodeVoteTransition {
-        let pro_tx_hash_buffer = hex::decode(&pro_tx_hash).unwrap();
-        let identifier_string = general_purpose::STANDARD.encode(pro_tx_hash_buffer);
-        let validator_identifier = Identifier::from_string(&identifier_string, Base64).unwrap();
+                                                   choice: ResourceVoteChoice) -> Result<MasternodeVoteTransition, Error> {
+        let validator_identifier = hex::decode(pro_tx_hash)
+            .ok()
+            .and_then(|bytes| Identifier::from_bytes(&bytes).ok())
+            .ok_or(Error::CommandLineArgumentInvalidInput(CommandLineArgumentInvalidInput::from("Could not decode pro tx hash, it should be 32 bytes in hex")))?;
 
         let vote = Vote::ResourceVote(ResourceVote::V0(ResourceVoteV0 {
             vote_poll: VotePoll::ContestedDocumentResourceVotePoll(ContestedDocumentResourceVotePoll {
@@ -46,6 +46,6 @@ impl Factories {
             signature: Default::default(),
         };
 
-        MasternodeVoteTransition::from(masternode_vote_transition)
+        Ok(MasternodeVoteTransition::from(masternode_vote_transition))
     }
 }
\ No newline at end of file
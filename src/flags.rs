@@ -1,37 +1,45 @@
-use clap::Subcommand;
-
-#[derive(clap::Parser, Debug)]
-#[command(version)]
-pub struct Flags {
-    /// Dash Platform server hostname or IPv4 address
-    #[arg(short = 'i', long = "address")]
-    pub server_address: String,
-
-    /// Dash Core IP port
-    #[arg(short = 'c', long)]
-    pub core_port: u16,
-
-    // Dash Core RPC user
-    #[arg(short = 'u', long)]
-    pub core_user: String,
-
-    // Dash Core RPC password
-    #[arg(short = 'p', long)]
-    pub core_password: String,
-
-    /// Dash Platform DAPI port
-    #[arg(short = 'd', long)]
-    pub platform_port: u16,
-}
+use crate::output::OutputFormat;
+
+/// Flags shared by all commands
+#[derive(clap::Args, Debug)]
+pub struct GlobalFlags {
+    /// Profile from the config file to take default values from
+    #[arg(long, global = true, env = "PLATFORM_CLI_PROFILE")]
+    pub profile: Option<String>,
+
+    /// Path to the config file, defaults to ~/.config/platform-cli/config.toml
+    #[arg(long, global = true, env = "PLATFORM_CLI_CONFIG")]
+    pub config: Option<String>,
+
+    /// Path to the encrypted keystore file, defaults to ~/.config/platform-cli/keystore.json
+    #[arg(long, global = true, env = "PLATFORM_CLI_KEYSTORE")]
+    pub keystore: Option<String>,
+
+    /// Path to the file tracking nonces of pending state transitions, defaults to ~/.config/platform-cli/nonces.json
+    #[arg(long, global = true, env = "PLATFORM_CLI_NONCE_STATE")]
+    pub nonce_state: Option<String>,
+
+    /// Output format, text or json
+    #[arg(long, global = true, env = "PLATFORM_CLI_OUTPUT", value_enum, default_value_t = OutputFormat::Text)]
+    pub output: OutputFormat,
+
+    /// Connect timeout for DAPI requests, in seconds
+    #[arg(long, global = true, env = "PLATFORM_CLI_CONNECT_TIMEOUT")]
+    pub connect_timeout: Option<u64>,
+
+    /// Request timeout for DAPI requests, in seconds
+    #[arg(long, global = true, env = "PLATFORM_CLI_REQUEST_TIMEOUT")]
+    pub request_timeout: Option<u64>,
+
+    /// How many times a failed DAPI request is repeated, defaults to 3
+    #[arg(long, global = true, env = "PLATFORM_CLI_RETRIES")]
+    pub retries: Option<usize>,
 
+    /// Delay before the first retry in milliseconds, doubled on every next one, defaults to 500
+    #[arg(long, global = true, env = "PLATFORM_CLI_RETRY_BACKOFF")]
+    pub retry_backoff: Option<u64>,
 
-#[derive(Subcommand, Debug, Clone)]
-enum Commands {
-    Get(String),
-    Set {
-        key: String,
-        value: String,
-        is_true: bool
-    },
-    Help
+    /// Ban DAPI endpoints that failed a request, so retries go to other nodes (true or false)
+    #[arg(long, global = true, env = "PLATFORM_CLI_BAN_FAILED_ADDRESS")]
+    pub ban_failed_address: Option<bool>,
 }
@@ -1,17 +1,86 @@
 use dapi_grpc::platform::v0::{BroadcastStateTransitionRequest};
-use dpp::serialization::PlatformSerializable;
+use dpp::consensus::ConsensusError;
+use dpp::dashcore::hashes::{sha256, Hash};
+use dpp::serialization::{PlatformDeserializable, PlatformSerializable};
 use dpp::state_transition::StateTransition;
-use rs_dapi_client::{DapiRequestExecutor, RequestSettings};
+use log::debug;
+use rs_dapi_client::{DapiClientError, DapiRequestExecutor, RequestSettings};
+use tonic::{Code, Status};
+use crate::errors::broadcast_error::BroadcastError;
+use crate::errors::Error;
 use crate::grpc::{PlatformGRPCClient};
 
+/// gRPC metadata key, under which Drive returns the serialized consensus error of the rejected transition
+const CONSENSUS_ERROR_METADATA_KEY: &str = "dash-serialized-consensus-error-bin";
+
 impl PlatformGRPCClient {
-    pub async fn broadcast_state_transition(&self, state_transition: StateTransition) -> () {
-        let buffer = state_transition.serialize_to_bytes().expect("Could not serialize state transition to buffer");
+    /// Broadcasts the state transition, retrying on transient failures.
+    /// Before every retry the network is asked whether the previous attempt was accepted already,
+    /// so the same transition is never sent twice
+    pub async fn broadcast_state_transition(&self, state_transition: StateTransition) -> Result<(), Error> {
+        let buffer = state_transition.serialize_to_bytes()?;
+        let state_transition_hash = sha256::Hash::hash(&buffer).to_byte_array();
 
         let broadcast_req = BroadcastStateTransitionRequest {
             state_transition: buffer,
         };
 
-        self.dapi_client.execute(broadcast_req, RequestSettings::default()).await.unwrap();
+        let mut attempt = 0;
+
+        loop {
+            if attempt > 0 && self.is_state_transition_accepted(&state_transition_hash).await? {
+                debug!("State transition was accepted on the previous attempt");
+
+                return Ok(())
+            }
+
+            match self.dapi_client.execute(broadcast_req.clone(), RequestSettings::default()).await {
+                Ok(_) => return Ok(()),
+                Err(DapiClientError::Transport(status, _)) if status.code() == Code::AlreadyExists => {
+                    debug!("State transition is already known to the network: {}", status.message());
+
+                    return Ok(())
+                }
+                Err(err) if attempt < self.retries && PlatformGRPCClient::is_transient_broadcast_error(&err) => {
+                    let backoff = self.backoff(attempt);
+
+                    attempt += 1;
+
+                    debug!("broadcastStateTransition request failed: {}, retrying in {} ms (attempt {} of {})", err, backoff.as_millis(), attempt, self.retries);
+
+                    tokio::time::sleep(backoff).await;
+                }
+                Err(DapiClientError::Transport(status, _)) => return Err(PlatformGRPCClient::broadcast_error(&status)),
+                Err(err) => return Err(PlatformGRPCClient::dapi_error(err, None)),
+            }
+        }
+    }
+
+    /// Besides the transient errors of all requests, a broadcast is repeated on internal errors of the node
+    /// without a consensus error, the acceptance check before the retry prevents sending it twice
+    fn is_transient_broadcast_error(err: &DapiClientError<Status>) -> bool {
+        match err {
+            DapiClientError::Transport(status, _) if status.code() == Code::Internal => status.metadata().get_bin(CONSENSUS_ERROR_METADATA_KEY).is_none(),
+            _ => PlatformGRPCClient::is_transient(err),
+        }
+    }
+
+    /// Decodes the consensus error from the status metadata, falls back to the status message
+    fn broadcast_error(status: &Status) -> Error {
+        status.metadata()
+            .get_bin(CONSENSUS_ERROR_METADATA_KEY)
+            .and_then(|value| value.to_bytes().ok())
+            .and_then(|bytes| PlatformGRPCClient::consensus_error(&bytes))
+            .unwrap_or(Error::BroadcastError(BroadcastError::from(format!("{} ({})", status.message(), status.code()).as_str())))
+    }
+
+    pub(super) fn consensus_error(bytes: &[u8]) -> Option<Error> {
+        match ConsensusError::deserialize_from_bytes(bytes) {
+            Ok(consensus_error) => Some(Error::ConsensusError(consensus_error.into())),
+            Err(err) => {
+                debug!("Could not decode consensus error: {}", err);
+                None
+            }
+        }
     }
-}
\ No newline at end of file
+}
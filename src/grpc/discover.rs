@@ -0,0 +1,56 @@
+use std::str::FromStr;
+use http::Uri;
+use log::{debug, info};
+use rs_dapi_client::{AddressList, DapiClient, RequestSettings};
+use crate::core_rpc::CoreRPCClient;
+use crate::errors::dapi_response_error::DapiResponseError;
+use crate::errors::Error;
+use crate::grpc::{DapiSettings, PlatformGRPCClient};
+
+impl PlatformGRPCClient {
+    /// Builds a client over all enabled evonodes from the masternode list.
+    /// Nodes that fail a request get banned (unless disabled) and the request is retried on another one
+    pub async fn discover(core_rpc_client: &CoreRPCClient, settings: DapiSettings) -> Result<PlatformGRPCClient, Error> {
+        let masternodes = core_rpc_client.get_masternode_list().await?;
+
+        let mut address_list = AddressList::new();
+
+        for dapi_url in masternodes.iter().filter(|masternode| masternode.is_enabled_evonode()).filter_map(|masternode| masternode.dapi_url()) {
+            match Uri::from_str(&dapi_url) {
+                Ok(uri) => {
+                    address_list.add_uri(uri);
+                }
+                Err(_) => debug!("Skipping evonode with invalid DAPI address {}", dapi_url)
+            }
+        }
+
+        if address_list.is_empty() {
+            return Err(Error::DapiResponseError(DapiResponseError::from("No enabled evonodes found in the masternode list")));
+        }
+
+        info!("Discovered {} enabled evonodes from the masternode list", address_list.len());
+
+        Ok(PlatformGRPCClient {
+            dapi_client: DapiClient::new(
+                address_list,
+                RequestSettings {
+                    ban_failed_address: settings.request_settings.ban_failed_address.or(Some(true)),
+                    ..settings.request_settings
+                }
+            ),
+            retries: settings.retries,
+            retry_backoff: settings.retry_backoff,
+        })
+    }
+
+    /// Uses DAPI URL if it is given, otherwise discovers endpoints through the Core RPC
+    pub async fn from_args(dapi_url: &str, core_rpc_url: &str, settings: DapiSettings) -> Result<PlatformGRPCClient, Error> {
+        if !dapi_url.is_empty() {
+            return Ok(PlatformGRPCClient::new(dapi_url, settings));
+        }
+
+        let core_rpc_client = CoreRPCClient::new(core_rpc_url)?;
+
+        PlatformGRPCClient::discover(&core_rpc_client, settings).await
+    }
+}
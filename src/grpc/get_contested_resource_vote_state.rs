@@ -0,0 +1,53 @@
+use dapi_grpc::platform::v0::{get_contested_resource_vote_state_request, get_contested_resource_vote_state_response, GetContestedResourceVoteStateRequest};
+use dapi_grpc::platform::v0::get_contested_resource_vote_state_request::GetContestedResourceVoteStateRequestV0;
+use dapi_grpc::platform::v0::get_contested_resource_vote_state_request::get_contested_resource_vote_state_request_v0::ResultType;
+use dapi_grpc::platform::v0::get_contested_resource_vote_state_response::get_contested_resource_vote_state_response_v0;
+use dapi_grpc::platform::v0::get_contested_resource_vote_state_response::get_contested_resource_vote_state_response_v0::ContestedResourceContenders;
+use dpp::bincode;
+use dpp::identifier::Identifier;
+use dpp::platform_value::Value;
+use rs_dapi_client::{DapiRequestExecutor, RequestSettings};
+use crate::errors::Error;
+use crate::grpc::{PlatformGRPCClient};
+
+impl PlatformGRPCClient {
+    /// Fetches contenders with their vote tallies of the contested resource poll, ex. DPNS domain index values ["dash", "b0b"].
+    /// Contenders are empty if there is no poll on the index values
+    pub async fn get_contested_resource_vote_state(&self, data_contract_identifier: Identifier, document_type_name: &str, index_name: &str, index_values: Vec<Value>) -> Result<ContestedResourceContenders, Error> {
+        let index_values = index_values
+            .iter()
+            .map(|value| bincode::encode_to_vec(value, bincode::config::standard()).expect("Could not encode index value"))
+            .collect();
+
+        let request = GetContestedResourceVoteStateRequest {
+            version: Some(get_contested_resource_vote_state_request::Version::V0(GetContestedResourceVoteStateRequestV0 {
+                contract_id: data_contract_identifier.to_vec(),
+                document_type_name: String::from(document_type_name),
+                index_name: String::from(index_name),
+                index_values,
+                result_type: ResultType::DocumentsAndVoteTally as i32,
+                allow_include_locked_and_abstaining_vote_tally: true,
+                start_at_identifier_info: None,
+                count: None,
+                prove: false,
+            }))
+        };
+
+        let response = self.with_retries("getContestedResourceVoteState", || self.dapi_client.execute(request.clone(), RequestSettings::default())).await
+            .map_err(|err| PlatformGRPCClient::dapi_error(err, None))?;
+
+        let data = response.version
+            .ok_or(PlatformGRPCClient::unexpected_response("getContestedResourceVoteState"))?;
+
+        match data {
+            get_contested_resource_vote_state_response::Version::V0(v0) => {
+                match v0.result {
+                    Some(get_contested_resource_vote_state_response_v0::Result::ContestedResourceContenders(contenders)) => {
+                        Ok(contenders)
+                    }
+                    _ => Err(PlatformGRPCClient::unexpected_response("getContestedResourceVoteState"))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use dapi_grpc::platform::v0::{get_data_contract_request, get_data_contract_response, GetDataContractRequest};
+use dapi_grpc::platform::v0::get_data_contract_request::GetDataContractRequestV0;
+use dapi_grpc::platform::v0::get_data_contract_response::get_data_contract_response_v0;
+use dpp::data_contract::DataContract;
+use dpp::prelude::Identifier;
+use dpp::serialization::PlatformDeserializableWithPotentialValidationFromVersionedStructure;
+use dpp::version::PlatformVersion;
+use rs_dapi_client::{DapiRequestExecutor, RequestSettings};
+use crate::errors::dapi_response_error::DapiResponseError;
+use crate::errors::Error;
+use crate::grpc::{PlatformGRPCClient};
+
+impl PlatformGRPCClient {
+    pub async fn get_data_contract(&self, identifier: Identifier) -> Result<DataContract, Error> {
+        let request = GetDataContractRequest {
+            version: Some(get_data_contract_request::Version::V0(GetDataContractRequestV0 {
+                id: identifier.to_vec(),
+                prove: false,
+            }))
+        };
+
+        let response = self.with_retries("getDataContract", || self.dapi_client.execute(request.clone(), RequestSettings::default())).await
+            .map_err(|err| PlatformGRPCClient::dapi_error(err, None))?;
+
+        let data = response.version
+            .ok_or(PlatformGRPCClient::unexpected_response("getDataContract"))?;
+
+        match data {
+            get_data_contract_response::Version::V0(v0) => {
+                match v0.result {
+                    Some(get_data_contract_response_v0::Result::DataContract(bytes)) => {
+                        DataContract::versioned_deserialize(bytes.as_slice(), false, PlatformVersion::latest())
+                            .map_err(|err| Error::DapiResponseError(DapiResponseError::from(format!("Could not decode data contract {}: {}", identifier, err).as_str())))
+                    }
+                    _ => Err(PlatformGRPCClient::unexpected_response("getDataContract"))
+                }
+            }
+        }
+    }
+}
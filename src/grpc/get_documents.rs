@@ -0,0 +1,88 @@
+use dapi_grpc::platform::v0::{get_documents_request, get_documents_response, GetDocumentsRequest};
+use dapi_grpc::platform::v0::get_documents_request::GetDocumentsRequestV0;
+use dapi_grpc::platform::v0::get_documents_request::get_documents_request_v0::Start;
+use dapi_grpc::platform::v0::get_documents_response::get_documents_response_v0;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::DataContract;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::document::serialization_traits::DocumentPlatformConversionMethodsV0;
+use dpp::platform_value::Value;
+use dpp::version::PlatformVersion;
+use rs_dapi_client::{DapiRequestExecutor, RequestSettings};
+use crate::errors::dapi_response_error::DapiResponseError;
+use crate::errors::Error;
+use crate::grpc::{PlatformGRPCClient};
+
+/// Platform returns at most 100 documents per query
+const DOCUMENTS_PAGE_SIZE: u32 = 100;
+
+impl PlatformGRPCClient {
+    /// Queries documents of the given type, where and order by clauses are arrays of clauses, ex. [["$ownerId", "==", id]]
+    pub async fn get_documents(&self, data_contract: &DataContract, document_type_name: &str, where_clauses: Value, order_by: Value, limit: u32) -> Result<Vec<Document>, Error> {
+        self.get_documents_page(data_contract, document_type_name, &where_clauses, &order_by, limit, None).await
+    }
+
+    /// Queries all documents matching the clauses, requesting pages after the last received document until a short page is returned
+    pub async fn get_all_documents(&self, data_contract: &DataContract, document_type_name: &str, where_clauses: Value, order_by: Value) -> Result<Vec<Document>, Error> {
+        let mut documents: Vec<Document> = Vec::new();
+
+        loop {
+            let start = documents.last().map(|document| Start::StartAfter(document.id().to_vec()));
+
+            let page = self.get_documents_page(data_contract, document_type_name, &where_clauses, &order_by, DOCUMENTS_PAGE_SIZE, start).await?;
+            let page_size = page.len();
+
+            documents.extend(page);
+
+            if page_size < DOCUMENTS_PAGE_SIZE as usize {
+                return Ok(documents);
+            }
+        }
+    }
+
+    async fn get_documents_page(&self, data_contract: &DataContract, document_type_name: &str, where_clauses: &Value, order_by: &Value, limit: u32, start: Option<Start>) -> Result<Vec<Document>, Error> {
+        let document_type = data_contract.document_type_for_name(document_type_name)
+            .map_err(|err| Error::DapiResponseError(DapiResponseError::from(err.to_string().as_str())))?;
+
+        let request = GetDocumentsRequest {
+            version: Some(get_documents_request::Version::V0(GetDocumentsRequestV0 {
+                data_contract_id: data_contract.id().to_vec(),
+                document_type: String::from(document_type_name),
+                r#where: PlatformGRPCClient::encode_cbor(where_clauses),
+                order_by: PlatformGRPCClient::encode_cbor(order_by),
+                limit,
+                prove: false,
+                start,
+            }))
+        };
+
+        let response = self.with_retries("getDocuments", || self.dapi_client.execute(request.clone(), RequestSettings::default())).await
+            .map_err(|err| PlatformGRPCClient::dapi_error(err, None))?;
+
+        let data = response.version
+            .ok_or(PlatformGRPCClient::unexpected_response("getDocuments"))?;
+
+        match data {
+            get_documents_response::Version::V0(v0) => {
+                match v0.result {
+                    Some(get_documents_response_v0::Result::Documents(documents)) => {
+                        documents.documents
+                            .iter()
+                            .map(|bytes| Document::from_bytes(bytes.as_slice(), document_type, PlatformVersion::latest())
+                                .map_err(|err| Error::DapiResponseError(DapiResponseError::from(format!("Could not decode {} document: {}", document_type_name, err).as_str()))))
+                            .collect()
+                    }
+                    _ => Err(PlatformGRPCClient::unexpected_response("getDocuments"))
+                }
+            }
+        }
+    }
+
+    fn encode_cbor(value: &Value) -> Vec<u8> {
+        let mut buffer: Vec<u8> = Vec::new();
+
+        ciborium::ser::into_writer(value, &mut buffer).expect("Could not encode query clauses to CBOR");
+
+        buffer
+    }
+}
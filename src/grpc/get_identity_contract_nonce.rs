@@ -4,10 +4,12 @@ use dapi_grpc::platform::v0::get_identity_contract_nonce_response::get_identity_
 use dpp::identifier::Identifier;
 use dpp::prelude::IdentityNonce;
 use rs_dapi_client::{DapiRequestExecutor, RequestSettings};
+use crate::errors::Error;
+use crate::errors::identity_not_found_error::IdentityNotFoundError;
 use crate::grpc::{PlatformGRPCClient};
 
 impl PlatformGRPCClient {
-    pub async fn get_identity_contract_nonce(&self, identifier: Identifier, data_contract_identifier: Identifier) -> IdentityNonce {
+    pub async fn get_identity_contract_nonce(&self, identifier: Identifier, data_contract_identifier: Identifier) -> Result<IdentityNonce, Error> {
         let request = GetIdentityContractNonceRequest {
             version: Some(get_identity_contract_nonce_request::Version::V0(GetIdentityContractNonceRequestV0 {
                 identity_id: identifier.to_vec(),
@@ -16,25 +18,21 @@ impl PlatformGRPCClient {
             }))
         };
 
-        let response = self.dapi_client.execute(request, RequestSettings::default()).await.unwrap();
+        let response = self.with_retries("getIdentityContractNonce", || self.dapi_client.execute(request.clone(), RequestSettings::default())).await
+            .map_err(|err| PlatformGRPCClient::dapi_error(err, Some(Error::IdentityNotFoundError(IdentityNotFoundError::from(identifier)))))?;
 
-        let data = response.version.unwrap();
+        let data = response.version
+            .ok_or(PlatformGRPCClient::unexpected_response("getIdentityContractNonce"))?;
 
-        let identity_nonce: IdentityNonce = match data {
+        match data {
             get_identity_contract_nonce_response::Version::V0(v0) => {
-                let result = v0.result.unwrap();
-
-                match result {
-                    get_identity_contract_nonce_response_v0::Result::IdentityContractNonce(nonce) => {
-                        IdentityNonce::from(nonce)
-                    }
-                    get_identity_contract_nonce_response_v0::Result::Proof(_) => {
-                        panic!("We don't expect proofs")
+                match v0.result {
+                    Some(get_identity_contract_nonce_response_v0::Result::IdentityContractNonce(nonce)) => {
+                        Ok(IdentityNonce::from(nonce))
                     }
+                    _ => Err(PlatformGRPCClient::unexpected_response("getIdentityContractNonce"))
                 }
             }
-        };
-
-        identity_nonce
+        }
     }
-}
\ No newline at end of file
+}
@@ -4,9 +4,7 @@ use dapi_grpc::platform::v0::get_identity_response::get_identity_response_v0;
 use dpp::identity::Identity;
 use dpp::prelude::Identifier;
 use dpp::serialization::PlatformDeserializable;
-use rs_dapi_client::{DapiClientError, DapiRequestExecutor, RequestSettings};
-use rs_dapi_client::address_list::AddressListError;
-use tonic::{Code};
+use rs_dapi_client::{DapiRequestExecutor, RequestSettings};
 use crate::errors::dapi_response_error::DapiResponseError;
 use crate::errors::Error;
 use crate::errors::identity_not_found_error::IdentityNotFoundError;
@@ -21,54 +19,22 @@ impl PlatformGRPCClient {
             }))
         };
 
-        let response = self.dapi_client.execute(request, RequestSettings::default()).await;
+        let response = self.with_retries("getIdentity", || self.dapi_client.execute(request.clone(), RequestSettings::default())).await
+            .map_err(|err| PlatformGRPCClient::dapi_error(err, Some(Error::IdentityNotFoundError(IdentityNotFoundError::from(identifier)))))?;
 
-        let result = response
-            .map(|get_identity_response|{
-                let data = get_identity_response.version.unwrap();
+        let data = response.version
+            .ok_or(PlatformGRPCClient::unexpected_response("getIdentity"))?;
 
-                let identity: Identity = match data {
-                    get_identity_response::Version::V0(v0) => {
-                        let result = v0.result.unwrap();
-
-                        match result {
-                            get_identity_response_v0::Result::Identity(bytes) => {
-                                Identity::deserialize_from_bytes(bytes.as_slice()).unwrap()
-                            }
-                            get_identity_response_v0::Result::Proof(_) => {
-                                panic!("We don't expect proofs")
-                            }
-                        }
-                    }
-                };
-
-                return identity
-            })
-            .map_err(|dapi_client_error| {
-                match dapi_client_error {
-                    DapiClientError::Transport(status, _) => {
-                        if status.code() == Code::NotFound {
-                            return Error::IdentityNotFoundError(IdentityNotFoundError::from(identifier))
-                        }
-
-                        return Error::DapiResponseError(DapiResponseError::from(format!("Unknown DAPI Response, status code: {}, message: {}", status.code(), status.message()).as_str()))
-                    }
-                    DapiClientError::NoAvailableAddresses => {
-                        return Error::DapiResponseError(DapiResponseError::from("No available addresses"))
-                    }
-                    DapiClientError::AddressList(addresses) => {
-                        return match addresses {
-                            AddressListError::AddressNotFound(url) => {
-                                Error::DapiResponseError(DapiResponseError::from(format!("Invalid DAPI endpoint address {}", url.to_string()).as_str()))
-                            }
-                        }
-                    }
-                    DapiClientError::Mock(_) => {
-                        return Error::DapiResponseError(DapiResponseError::from("Mock dapi client response is not supported"))
+        match data {
+            get_identity_response::Version::V0(v0) => {
+                match v0.result {
+                    Some(get_identity_response_v0::Result::Identity(bytes)) => {
+                        Identity::deserialize_from_bytes(bytes.as_slice())
+                            .map_err(|err| Error::DapiResponseError(DapiResponseError::from(format!("Could not decode identity {}: {}", identifier, err).as_str())))
                     }
+                    _ => Err(PlatformGRPCClient::unexpected_response("getIdentity"))
                 }
-            });
-
-        result
+            }
+        }
     }
-}
\ No newline at end of file
+}
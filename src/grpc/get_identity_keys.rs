@@ -6,10 +6,13 @@ use dpp::identifier::Identifier;
 use dpp::identity::{IdentityPublicKey};
 use dpp::serialization::PlatformDeserializable;
 use rs_dapi_client::{DapiRequestExecutor, RequestSettings};
+use crate::errors::dapi_response_error::DapiResponseError;
+use crate::errors::Error;
+use crate::errors::identity_not_found_error::IdentityNotFoundError;
 use crate::grpc::{PlatformGRPCClient};
 
 impl PlatformGRPCClient {
-    pub async fn get_identity_keys(&self, identifier: Identifier) -> Vec<IdentityPublicKey> {
+    pub async fn get_identity_keys(&self, identifier: Identifier) -> Result<Vec<IdentityPublicKey>, Error> {
         let request = GetIdentityKeysRequest {
             version: Some(get_identity_keys_request::Version::V0(GetIdentityKeysRequestV0 {
                 identity_id: identifier.to_vec(),
@@ -20,34 +23,25 @@ impl PlatformGRPCClient {
             }))
         };
 
-        let response = self.dapi_client.execute(request, RequestSettings::default()).await.unwrap();
+        let response = self.with_retries("getIdentityKeys", || self.dapi_client.execute(request.clone(), RequestSettings::default())).await
+            .map_err(|err| PlatformGRPCClient::dapi_error(err, Some(Error::IdentityNotFoundError(IdentityNotFoundError::from(identifier)))))?;
 
-        let data = response.version.unwrap();
+        let data = response.version
+            .ok_or(PlatformGRPCClient::unexpected_response("getIdentityKeys"))?;
 
-        let identity_public_keys: Vec<IdentityPublicKey> = match data {
+        match data {
             get_identity_keys_response::Version::V0(v0) => {
-                let result = v0.result.unwrap();
-
-                match result {
-                    get_identity_keys_response_v0::Result::Keys(keys) => {
-                        let tas = keys.keys_bytes
-                            .into_iter()
-                            .map(|key| {
-                                IdentityPublicKey::deserialize_from_bytes(key.as_slice()).unwrap()
-                            })
-                            .collect::<Vec<IdentityPublicKey>>()
-                            .try_into()
-                            .unwrap();
-
-                        tas
-                    }
-                    get_identity_keys_response_v0::Result::Proof(_) => {
-                        panic!("We don't expect proofs")
+                match v0.result {
+                    Some(get_identity_keys_response_v0::Result::Keys(keys)) => {
+                        keys.keys_bytes
+                            .iter()
+                            .map(|key| IdentityPublicKey::deserialize_from_bytes(key.as_slice())
+                                .map_err(|err| Error::DapiResponseError(DapiResponseError::from(format!("Could not decode public key of identity {}: {}", identifier, err).as_str()))))
+                            .collect()
                     }
+                    _ => Err(PlatformGRPCClient::unexpected_response("getIdentityKeys"))
                 }
             }
-        };
-
-        identity_public_keys
+        }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,36 @@
+use dapi_grpc::platform::v0::{get_prefunded_specialized_balance_request, get_prefunded_specialized_balance_response, GetPrefundedSpecializedBalanceRequest};
+use dapi_grpc::platform::v0::get_prefunded_specialized_balance_request::GetPrefundedSpecializedBalanceRequestV0;
+use dapi_grpc::platform::v0::get_prefunded_specialized_balance_response::get_prefunded_specialized_balance_response_v0;
+use dpp::identifier::Identifier;
+use rs_dapi_client::{DapiRequestExecutor, RequestSettings};
+use crate::errors::Error;
+use crate::grpc::{PlatformGRPCClient};
+
+impl PlatformGRPCClient {
+    /// Fetches credits left on the prefunded balance, ex. the voting balance of a contested resource poll
+    pub async fn get_prefunded_specialized_balance(&self, balance_identifier: Identifier) -> Result<u64, Error> {
+        let request = GetPrefundedSpecializedBalanceRequest {
+            version: Some(get_prefunded_specialized_balance_request::Version::V0(GetPrefundedSpecializedBalanceRequestV0 {
+                id: balance_identifier.to_vec(),
+                prove: false,
+            }))
+        };
+
+        let response = self.with_retries("getPrefundedSpecializedBalance", || self.dapi_client.execute(request.clone(), RequestSettings::default())).await
+            .map_err(|err| PlatformGRPCClient::dapi_error(err, None))?;
+
+        let data = response.version
+            .ok_or(PlatformGRPCClient::unexpected_response("getPrefundedSpecializedBalance"))?;
+
+        match data {
+            get_prefunded_specialized_balance_response::Version::V0(v0) => {
+                match v0.result {
+                    Some(get_prefunded_specialized_balance_response_v0::Result::Balance(balance)) => {
+                        Ok(balance)
+                    }
+                    _ => Err(PlatformGRPCClient::unexpected_response("getPrefundedSpecializedBalance"))
+                }
+            }
+        }
+    }
+}
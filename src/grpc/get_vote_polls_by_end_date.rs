@@ -0,0 +1,88 @@
+use dapi_grpc::platform::v0::{get_vote_polls_by_end_date_request, get_vote_polls_by_end_date_response, GetVotePollsByEndDateRequest};
+use dapi_grpc::platform::v0::get_vote_polls_by_end_date_request::GetVotePollsByEndDateRequestV0;
+use dapi_grpc::platform::v0::get_vote_polls_by_end_date_request::get_vote_polls_by_end_date_request_v0::StartAtTimeInfo;
+use dapi_grpc::platform::v0::get_vote_polls_by_end_date_response::get_vote_polls_by_end_date_response_v0;
+use dpp::serialization::PlatformDeserializable;
+use dpp::voting::vote_polls::VotePoll;
+use rs_dapi_client::{DapiRequestExecutor, RequestSettings};
+use crate::errors::dapi_response_error::DapiResponseError;
+use crate::errors::Error;
+use crate::grpc::{PlatformGRPCClient};
+
+const VOTE_POLLS_PAGE_SIZE: u32 = 100;
+
+impl PlatformGRPCClient {
+    /// Fetches all vote polls that are not resolved yet, paired with their end time in milliseconds
+    pub async fn get_vote_polls_by_end_date(&self) -> Result<Vec<(u64, VotePoll)>, Error> {
+        let mut vote_polls: Vec<(u64, VotePoll)> = Vec::new();
+        let mut start_time_info: Option<StartAtTimeInfo> = None;
+        // polls of the last timestamp of the previous page, the page may end in the middle of them
+        let mut last_timestamp_polls: Vec<Vec<u8>> = Vec::new();
+
+        loop {
+            let request = GetVotePollsByEndDateRequest {
+                version: Some(get_vote_polls_by_end_date_request::Version::V0(GetVotePollsByEndDateRequestV0 {
+                    start_time_info: start_time_info.clone(),
+                    end_time_info: None,
+                    limit: Some(VOTE_POLLS_PAGE_SIZE),
+                    offset: None,
+                    ascending: true,
+                    prove: false,
+                }))
+            };
+
+            let response = self.with_retries("getVotePollsByEndDate", || self.dapi_client.execute(request.clone(), RequestSettings::default())).await
+                .map_err(|err| PlatformGRPCClient::dapi_error(err, None))?;
+
+            let data = response.version
+                .ok_or(PlatformGRPCClient::unexpected_response("getVotePollsByEndDate"))?;
+
+            let page = match data {
+                get_vote_polls_by_end_date_response::Version::V0(v0) => {
+                    match v0.result {
+                        Some(get_vote_polls_by_end_date_response_v0::Result::VotePollsByTimestamps(page)) => page,
+                        _ => return Err(PlatformGRPCClient::unexpected_response("getVotePollsByEndDate"))
+                    }
+                }
+            };
+
+            let previous_timestamp = start_time_info.as_ref().map(|start_time_info| start_time_info.start_time_ms);
+            let mut new_vote_polls = 0;
+
+            for timestamp in &page.vote_polls_by_timestamps {
+                for bytes in &timestamp.serialized_vote_polls {
+                    if Some(timestamp.timestamp) == previous_timestamp && last_timestamp_polls.contains(bytes) {
+                        continue;
+                    }
+
+                    let vote_poll = VotePoll::deserialize_from_bytes(bytes.as_slice())
+                        .map_err(|err| Error::DapiResponseError(DapiResponseError::from(format!("Could not decode vote poll: {}", err).as_str())))?;
+
+                    vote_polls.push((timestamp.timestamp, vote_poll));
+                    new_vote_polls += 1;
+                }
+            }
+
+            let last = match page.vote_polls_by_timestamps.last() {
+                Some(last) if !page.finished_results => last,
+                _ => return Ok(vote_polls),
+            };
+
+            if new_vote_polls == 0 {
+                return Err(Error::DapiResponseError(DapiResponseError::from(format!("Could not page through vote polls, more than {} of them end at {}", VOTE_POLLS_PAGE_SIZE, last.timestamp).as_str())));
+            }
+
+            // the next page starts at the last timestamp again, polls sharing it could be cut off by the limit
+            if Some(last.timestamp) != previous_timestamp {
+                last_timestamp_polls.clear();
+            }
+            for bytes in &last.serialized_vote_polls {
+                if !last_timestamp_polls.contains(bytes) {
+                    last_timestamp_polls.push(bytes.clone());
+                }
+            }
+
+            start_time_info = Some(StartAtTimeInfo { start_time_ms: last.timestamp, start_time_included: true });
+        }
+    }
+}
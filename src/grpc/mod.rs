@@ -1,4 +1,9 @@
-use rs_dapi_client::{AddressList, DapiClient, RequestSettings};
+use std::time::Duration;
+use rs_dapi_client::{AddressList, DapiClient, DapiClientError, RequestSettings};
+use rs_dapi_client::address_list::AddressListError;
+use tonic::{Code, Status};
+use crate::errors::dapi_response_error::DapiResponseError;
+use crate::errors::Error;
 
 mod get_identity_by_public_key_hash;
 mod get_identity_keys;
@@ -6,19 +11,73 @@ mod get_identity_nonce;
 mod get_identity_contract_nonce;
 mod broadcast_state_transition;
 mod get_identity_identifier;
+mod discover;
+mod get_data_contract;
+mod get_documents;
+mod get_contested_resource_vote_state;
+mod get_vote_polls_by_end_date;
+mod get_prefunded_specialized_balance;
+mod retry;
+mod wait_for_state_transition_result;
+
+/// Timeouts and retry policy of the DAPI requests
+#[derive(Clone, Debug)]
+pub struct DapiSettings {
+    pub request_settings: RequestSettings,
+    /// How many times a failed request is repeated
+    pub retries: usize,
+    /// Delay before the first retry, doubled on every next one
+    pub retry_backoff: Duration,
+}
 
 pub struct PlatformGRPCClient {
     dapi_client: DapiClient,
+    retries: usize,
+    retry_backoff: Duration,
 }
 
 impl PlatformGRPCClient {
-    pub fn new(dapi_url: &str) -> PlatformGRPCClient {
+    pub fn new(dapi_url: &str, settings: DapiSettings) -> PlatformGRPCClient {
         return PlatformGRPCClient {
             dapi_client: DapiClient::new(
                 AddressList::from(dapi_url),
-                RequestSettings::default(),
+                settings.request_settings,
             ),
+            retries: settings.retries,
+            retry_backoff: settings.retry_backoff,
         };
     }
-}
 
+    /// Maps DAPI client failure to the CLI error, NotFound status is reported as `not_found` if given
+    fn dapi_error(dapi_client_error: DapiClientError<Status>, not_found: Option<Error>) -> Error {
+        match dapi_client_error {
+            DapiClientError::Transport(status, _) => {
+                if status.code() == Code::NotFound {
+                    if let Some(error) = not_found {
+                        return error
+                    }
+                }
+
+                Error::DapiResponseError(DapiResponseError::from(format!("Unknown DAPI Response, status code: {}, message: {}", status.code(), status.message()).as_str()))
+            }
+            DapiClientError::NoAvailableAddresses => {
+                Error::DapiResponseError(DapiResponseError::from("No available addresses"))
+            }
+            DapiClientError::AddressList(addresses) => {
+                match addresses {
+                    AddressListError::AddressNotFound(url) => {
+                        Error::DapiResponseError(DapiResponseError::from(format!("Invalid DAPI endpoint address {}", url.to_string()).as_str()))
+                    }
+                }
+            }
+            DapiClientError::Mock(_) => {
+                Error::DapiResponseError(DapiResponseError::from("Mock dapi client response is not supported"))
+            }
+        }
+    }
+
+    /// Response is missing its version or result, or contains a proof we did not ask for
+    fn unexpected_response(request_name: &str) -> Error {
+        Error::DapiResponseError(DapiResponseError::from(format!("Unexpected DAPI response to {} request", request_name).as_str()))
+    }
+}
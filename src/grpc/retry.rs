@@ -0,0 +1,77 @@
+use std::future::Future;
+use std::time::Duration;
+use log::debug;
+use rs_dapi_client::DapiClientError;
+use tonic::{Code, Status};
+use crate::grpc::PlatformGRPCClient;
+
+/// Upper limit of the delay between retries
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(30);
+
+impl PlatformGRPCClient {
+    /// Runs the request until it succeeds, fails with a non transient error or retries are exhausted,
+    /// waiting with exponential backoff between attempts
+    pub(super) async fn with_retries<T, F, Fut>(&self, request_name: &str, mut request: F) -> Result<T, DapiClientError<Status>>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, DapiClientError<Status>>>,
+    {
+        let mut attempt = 0;
+
+        loop {
+            match request().await {
+                Err(err) if attempt < self.retries && PlatformGRPCClient::is_transient(&err) => {
+                    let backoff = self.backoff(attempt);
+
+                    attempt += 1;
+
+                    debug!("{} request failed: {}, retrying in {} ms (attempt {} of {})", request_name, err, backoff.as_millis(), attempt, self.retries);
+
+                    tokio::time::sleep(backoff).await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    pub(super) fn backoff(&self, attempt: usize) -> Duration {
+        self.retry_backoff
+            .checked_mul(2u32.saturating_pow(attempt as u32))
+            .map(|backoff| backoff.min(MAX_RETRY_BACKOFF))
+            .unwrap_or(MAX_RETRY_BACKOFF)
+    }
+
+    /// Network failures and overloaded nodes are worth another attempt, rejected requests are not
+    pub(super) fn is_transient(err: &DapiClientError<Status>) -> bool {
+        match err {
+            DapiClientError::Transport(status, _) => PlatformGRPCClient::is_transient_code(status.code()),
+            DapiClientError::NoAvailableAddresses => true,
+            _ => false,
+        }
+    }
+
+    fn is_transient_code(code: Code) -> bool {
+        matches!(code, Code::Unavailable | Code::DeadlineExceeded | Code::ResourceExhausted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retries_network_and_overload_errors() {
+        for code in [Code::Unavailable, Code::DeadlineExceeded, Code::ResourceExhausted] {
+            assert!(PlatformGRPCClient::is_transient_code(code), "{:?} should be retried", code);
+        }
+
+        assert!(PlatformGRPCClient::is_transient(&DapiClientError::NoAvailableAddresses));
+    }
+
+    #[test]
+    fn does_not_retry_rejected_requests() {
+        for code in [Code::InvalidArgument, Code::NotFound, Code::AlreadyExists, Code::FailedPrecondition, Code::PermissionDenied, Code::Unauthenticated, Code::Unimplemented, Code::Aborted, Code::Unknown, Code::Internal] {
+            assert!(!PlatformGRPCClient::is_transient_code(code), "{:?} should not be retried", code);
+        }
+    }
+}
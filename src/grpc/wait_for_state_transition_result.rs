@@ -0,0 +1,53 @@
+use dapi_grpc::platform::v0::{wait_for_state_transition_result_request, wait_for_state_transition_result_response, WaitForStateTransitionResultRequest};
+use dapi_grpc::platform::v0::wait_for_state_transition_result_request::WaitForStateTransitionResultRequestV0;
+use dapi_grpc::platform::v0::wait_for_state_transition_result_response::wait_for_state_transition_result_response_v0;
+use std::time::Duration;
+use log::debug;
+use rs_dapi_client::{DapiRequestExecutor, RequestSettings};
+use crate::errors::broadcast_error::BroadcastError;
+use crate::errors::Error;
+use crate::grpc::{PlatformGRPCClient};
+
+/// How long to wait for the result of the previous broadcast attempt
+const STATE_TRANSITION_RESULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+impl PlatformGRPCClient {
+    /// Checks if the state transition with given hash was already accepted by the network.
+    /// Returns an error if it was processed and rejected, false if the result is unknown yet
+    pub async fn is_state_transition_accepted(&self, state_transition_hash: &[u8]) -> Result<bool, Error> {
+        let request = WaitForStateTransitionResultRequest {
+            version: Some(wait_for_state_transition_result_request::Version::V0(WaitForStateTransitionResultRequestV0 {
+                state_transition_hash: state_transition_hash.to_vec(),
+                prove: false,
+            }))
+        };
+
+        let settings = RequestSettings {
+            timeout: Some(STATE_TRANSITION_RESULT_TIMEOUT),
+            retries: Some(0),
+            ..RequestSettings::default()
+        };
+
+        let response = match self.dapi_client.execute(request, settings).await {
+            Ok(response) => response,
+            Err(err) => {
+                debug!("State transition result is not available: {}", err);
+
+                return Ok(false)
+            }
+        };
+
+        match response.version {
+            Some(wait_for_state_transition_result_response::Version::V0(v0)) => {
+                match v0.result {
+                    Some(wait_for_state_transition_result_response_v0::Result::Error(err)) => {
+                        Err(PlatformGRPCClient::consensus_error(&err.data)
+                            .unwrap_or(Error::BroadcastError(BroadcastError::from(format!("{} (code {})", err.message, err.code).as_str()))))
+                    }
+                    _ => Ok(true)
+                }
+            }
+            None => Ok(false)
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use dpp::BlsModule;
+use dpp::bls::native_bls::NativeBlsModule;
+use dpp::dashcore::hashes::{hash160, sha256, Hash};
+use dpp::dashcore::PrivateKey;
+use dpp::dashcore::secp256k1::Secp256k1;
+use dpp::dashcore::secp256k1::hashes::hex::DisplayHex;
+use dpp::identifier::Identifier;
+use dpp::identity::accessors::IdentityGettersV0;
+use dpp::identity::hash::IdentityPublicKeyHashMethodsV0;
+use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dpp::identity::{Identity, IdentityPublicKey, KeyType, Purpose, SecurityLevel};
+use dpp::platform_value::string_encoding::Encoding::Base58;
+use log::debug;
+use crate::errors::Error;
+use crate::errors::identity_public_key_hash_mismatch_error::IdentityPublicKeyHashMismatchError;
+use crate::errors::identity_public_key_requirement_error::IdentityPublicKeyRequirementError;
+use crate::grpc::PlatformGRPCClient;
+use crate::signer::{Signer, SIGNING_KEY_TYPES};
+use crate::utils::Utils;
+
+/// Checks whether the private key belongs to the identity key, deriving the public key according to the key type
+pub fn private_key_matches(identity_public_key: &IdentityPublicKey, private_key: &PrivateKey) -> bool {
+    let Ok(public_key_hash) = identity_public_key.public_key_hash() else {
+        return false;
+    };
+
+    match identity_public_key.key_type() {
+        KeyType::ECDSA_SECP256K1 | KeyType::ECDSA_HASH160 => {
+            let secp = Secp256k1::new();
+
+            private_key.public_key(&secp).pubkey_hash().to_byte_array() == public_key_hash
+        }
+        KeyType::BLS12_381 => {
+            match NativeBlsModule.private_key_to_public_key(private_key.to_bytes().as_slice()) {
+                Ok(public_key) => hash160::Hash::hash(public_key.as_slice()).to_byte_array() == public_key_hash,
+                Err(_) => false
+            }
+        }
+        KeyType::EDDSA_25519_HASH160 => {
+            let public_key = Signer::ed25519_signing_key(private_key).verifying_key().to_bytes();
+
+            // Tenderdash node id is the first 20 bytes of sha256 of the public key
+            let node_id: [u8; 20] = sha256::Hash::hash(&public_key).to_byte_array()[..20].try_into().unwrap();
+
+            hash160::Hash::hash(&public_key).to_byte_array() == public_key_hash || node_id == public_key_hash
+        }
+        _ => false
+    }
+}
+
+/// Purposes and security levels an identity key must have to sign a state transition
+pub struct KeyRequirements {
+    pub transition_name: &'static str,
+    pub purposes: Vec<Purpose>,
+    pub security_levels: Vec<SecurityLevel>,
+}
+
+impl KeyRequirements {
+    pub fn identity_credit_withdrawal() -> KeyRequirements {
+        KeyRequirements {
+            transition_name: "IdentityCreditWithdrawal",
+            purposes: vec![Purpose::TRANSFER, Purpose::OWNER],
+            security_levels: vec![SecurityLevel::CRITICAL],
+        }
+    }
+
+    /// Masternode rewards can only be withdrawn by the owner key to the registered payout address
+    pub fn masternode_owner_withdrawal() -> KeyRequirements {
+        KeyRequirements {
+            transition_name: "IdentityCreditWithdrawal",
+            purposes: vec![Purpose::OWNER],
+            security_levels: vec![SecurityLevel::CRITICAL],
+        }
+    }
+
+    pub fn identity_credit_transfer() -> KeyRequirements {
+        KeyRequirements {
+            transition_name: "IdentityCreditTransfer",
+            purposes: vec![Purpose::TRANSFER],
+            security_levels: vec![SecurityLevel::CRITICAL],
+        }
+    }
+
+    /// Identity keys can only be added or disabled with the master key
+    pub fn identity_update() -> KeyRequirements {
+        KeyRequirements {
+            transition_name: "IdentityUpdate",
+            purposes: vec![Purpose::AUTHENTICATION],
+            security_levels: vec![SecurityLevel::MASTER],
+        }
+    }
+
+    /// Documents can be signed with any non-master key at least as strong as required by the document type
+    pub fn documents_batch(security_level_requirement: SecurityLevel) -> KeyRequirements {
+        KeyRequirements {
+            transition_name: "DocumentsBatch",
+            purposes: vec![Purpose::AUTHENTICATION],
+            security_levels: vec![SecurityLevel::CRITICAL, SecurityLevel::HIGH, SecurityLevel::MEDIUM]
+                .into_iter()
+                .filter(|security_level| *security_level <= security_level_requirement)
+                .collect(),
+        }
+    }
+
+    pub fn masternode_vote() -> KeyRequirements {
+        KeyRequirements {
+            transition_name: "MasternodeVote",
+            purposes: vec![Purpose::VOTING],
+            security_levels: vec![SecurityLevel::CRITICAL, SecurityLevel::HIGH, SecurityLevel::MEDIUM],
+        }
+    }
+
+    pub fn is_satisfied_by(&self, identity_public_key: &IdentityPublicKey) -> bool {
+        identity_public_key.disabled_at().is_none()
+            && SIGNING_KEY_TYPES.contains(&identity_public_key.key_type())
+            && self.purposes.contains(&identity_public_key.purpose())
+            && self.security_levels.contains(&identity_public_key.security_level())
+    }
+
+    /// Fetches the identity with its public keys from the network and picks the key to sign the transition with
+    pub async fn find_signing_identity(&self,
+                                       platform_grpc_client: &PlatformGRPCClient,
+                                       identifier: Identifier,
+                                       private_keys: &[PrivateKey]) -> Result<(Identity, IdentityPublicKey, PrivateKey), Error> {
+        let identity = platform_grpc_client
+            .get_identity_by_identifier(identifier).await?;
+
+        debug!("Identity with identifier {} found in the network", identity.id());
+
+        let identity_public_keys = platform_grpc_client
+            .get_identity_keys(identity.id()).await?;
+
+        debug!("Finding matching IdentityPublicKey in the Identity against applied private keys");
+
+        let (identity_public_key, private_key) = self.select_signing_key(identity.id(), &identity_public_keys, private_keys)?;
+
+        debug!("Found matching IdentityPublicKey id: {}, key_type: {}, pubkeyhash: {}, purpose: {}, security_level: {}",
+            identity_public_key.id(),
+            identity_public_key.key_type(),
+            identity_public_key.public_key_hash().map(|hash| hash.to_lower_hex_string()).unwrap_or_default(),
+            identity_public_key.purpose(),
+            identity_public_key.security_level());
+
+        Ok((identity, identity_public_key, private_key))
+    }
+
+    /// Picks the identity key that matches one of the private keys and is allowed to sign the transition
+    pub fn select_signing_key(&self,
+                              identity_id: Identifier,
+                              identity_public_keys: &[IdentityPublicKey],
+                              private_keys: &[PrivateKey]) -> Result<(IdentityPublicKey, PrivateKey), Error> {
+        let matching_keys: Vec<(&IdentityPublicKey, &PrivateKey)> = private_keys
+            .iter()
+            .flat_map(|private_key| {
+                identity_public_keys
+                    .iter()
+                    .filter(move |key| private_key_matches(key, private_key))
+                    .map(move |key| (key, private_key))
+            })
+            .collect();
+
+        if matching_keys.is_empty() {
+            return Err(Error::IdentityPublicKeyHashMismatchError(IdentityPublicKeyHashMismatchError::from((identity_id, Utils::pubkey_hashes(private_keys)))));
+        }
+
+        if let Some((identity_public_key, private_key)) = matching_keys
+            .iter()
+            .find(|(identity_public_key, _)| self.is_satisfied_by(identity_public_key)) {
+            return Ok(((*identity_public_key).clone(), **private_key));
+        }
+
+        let acceptable_key_ids = identity_public_keys
+            .iter()
+            .filter(|key| self.is_satisfied_by(key))
+            .map(|key| key.id().to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let (identity_public_key, _) = matching_keys.first().unwrap();
+
+        let message = format!("Key id {} (purpose: {}, security level: {}, key type: {}{}) of the identity {} can not sign {}, it requires purpose {} with security level {} and key type {}. {}",
+                              identity_public_key.id(),
+                              identity_public_key.purpose(),
+                              identity_public_key.security_level(),
+                              identity_public_key.key_type(),
+                              if identity_public_key.disabled_at().is_some() { ", disabled" } else { "" },
+                              identity_id.to_string(Base58),
+                              self.transition_name,
+                              self.purposes.iter().map(|purpose| purpose.to_string()).collect::<Vec<String>>().join(" or "),
+                              self.security_levels.iter().map(|security_level| security_level.to_string()).collect::<Vec<String>>().join(" or "),
+                              SIGNING_KEY_TYPES.iter().map(|key_type| key_type.to_string()).collect::<Vec<String>>().join(" or "),
+                              match acceptable_key_ids.is_empty() {
+                                  true => String::from("The identity has no acceptable keys"),
+                                  false => format!("Acceptable key ids: {}", acceptable_key_ids)
+                              });
+
+        Err(Error::IdentityPublicKeyRequirementError(IdentityPublicKeyRequirementError::from(message.as_str())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dpp::dashcore::Network;
+    use crate::test_utils::{test_identity_public_key, test_private_key};
+    use super::*;
+
+    fn private_key(byte: u8) -> PrivateKey {
+        test_private_key(byte, Network::Testnet)
+    }
+
+    #[test]
+    fn matches_private_key_of_ecdsa_keys() {
+        let private_key = private_key(1);
+
+        let key = test_identity_public_key(0, KeyType::ECDSA_SECP256K1, Purpose::TRANSFER, SecurityLevel::CRITICAL, &private_key);
+        let hash_key = test_identity_public_key(1, KeyType::ECDSA_HASH160, Purpose::TRANSFER, SecurityLevel::CRITICAL, &private_key);
+
+        assert!(private_key_matches(&key, &private_key));
+        assert!(private_key_matches(&hash_key, &private_key));
+        assert!(!private_key_matches(&key, &self::private_key(2)));
+    }
+
+    #[test]
+    fn selects_key_satisfying_requirements() {
+        let private_key = private_key(1);
+        let keys = vec![
+            test_identity_public_key(0, KeyType::ECDSA_SECP256K1, Purpose::AUTHENTICATION, SecurityLevel::MASTER, &private_key),
+            test_identity_public_key(1, KeyType::ECDSA_SECP256K1, Purpose::TRANSFER, SecurityLevel::CRITICAL, &private_key),
+        ];
+
+        let (key, _) = KeyRequirements::identity_credit_transfer()
+            .select_signing_key(Identifier::default(), &keys, &[private_key])
+            .unwrap();
+
+        assert_eq!(key.id(), 1);
+    }
+
+    #[test]
+    fn reports_requirement_error_for_wrong_purpose() {
+        let private_key = private_key(1);
+        let keys = vec![test_identity_public_key(0, KeyType::ECDSA_SECP256K1, Purpose::AUTHENTICATION, SecurityLevel::HIGH, &private_key)];
+
+        let result = KeyRequirements::identity_credit_transfer().select_signing_key(Identifier::default(), &keys, &[private_key]);
+
+        assert!(matches!(result, Err(Error::IdentityPublicKeyRequirementError(_))));
+    }
+
+    #[test]
+    fn reports_hash_mismatch_for_unknown_private_key() {
+        let keys = vec![test_identity_public_key(0, KeyType::ECDSA_SECP256K1, Purpose::TRANSFER, SecurityLevel::CRITICAL, &private_key(1))];
+
+        let result = KeyRequirements::identity_credit_transfer().select_signing_key(Identifier::default(), &keys, &[private_key(2)]);
+
+        assert!(matches!(result, Err(Error::IdentityPublicKeyHashMismatchError(_))));
+    }
+
+    #[test]
+    fn never_selects_eddsa_keys() {
+        let private_key = private_key(1);
+        let key = test_identity_public_key(0, KeyType::EDDSA_25519_HASH160, Purpose::TRANSFER, SecurityLevel::CRITICAL, &private_key);
+
+        assert!(private_key_matches(&key, &private_key));
+        assert!(!KeyRequirements::identity_credit_transfer().is_satisfied_by(&key));
+    }
+
+    #[test]
+    fn documents_batch_allows_keys_at_least_as_strong_as_required() {
+        let requirements = KeyRequirements::documents_batch(SecurityLevel::HIGH);
+
+        assert_eq!(requirements.security_levels, vec![SecurityLevel::CRITICAL, SecurityLevel::HIGH]);
+    }
+}
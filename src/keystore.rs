@@ -0,0 +1,305 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use aes_gcm::{Aes256Gcm, Key, KeyInit, Nonce};
+use aes_gcm::aead::Aead;
+use dpp::dashcore::{Network, PrivateKey};
+use dpp::identifier::Identifier;
+use dpp::platform_value::string_encoding::Encoding::Base58;
+use rand::RngCore;
+use rand::rngs::OsRng;
+use scrypt::Params;
+use serde::{Deserialize, Serialize};
+use crate::errors::Error;
+use crate::errors::keystore_error::KeystoreError;
+
+const KEYSTORE_VERSION: u32 = 1;
+const KEYSTORE_PASSWORD_ENV: &str = "PLATFORM_CLI_KEYSTORE_PASSWORD";
+
+// scrypt N = 2^15, r = 8, p = 1
+const SCRYPT_LOG_N: u8 = 15;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+
+/// Private key encrypted with a password, together with its public metadata
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KeystoreEntry {
+    pub label: String,
+    pub network: String,
+    pub identity: Option<String>,
+    pub key_id: Option<u32>,
+    pub public_key: String,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct KeystoreFile {
+    version: u32,
+    entries: Vec<KeystoreEntry>,
+}
+
+/// Password-encrypted local storage of private keys (scrypt + AES-256-GCM)
+pub struct Keystore {
+    path: PathBuf,
+    entries: Vec<KeystoreEntry>,
+}
+
+fn keystore_error(message: &str) -> Error {
+    Error::KeystoreError(KeystoreError::from(message))
+}
+
+impl Keystore {
+    pub fn open(path: &Path) -> Result<Keystore, Error> {
+        if !path.exists() {
+            return Ok(Keystore { path: path.to_path_buf(), entries: vec![] });
+        }
+
+        let data = fs::read_to_string(path)
+            .map_err(|err| keystore_error(format!("could not read {}: {}", path.display(), err).as_str()))?;
+
+        let keystore_file: KeystoreFile = serde_json::from_str(&data)
+            .map_err(|err| keystore_error(format!("could not parse {}: {}", path.display(), err).as_str()))?;
+
+        if keystore_file.version != KEYSTORE_VERSION {
+            return Err(keystore_error(format!("unsupported keystore version {}", keystore_file.version).as_str()));
+        }
+
+        Ok(Keystore { path: path.to_path_buf(), entries: keystore_file.entries })
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|err| keystore_error(format!("could not create {}: {}", parent.display(), err).as_str()))?;
+        }
+
+        let keystore_file = KeystoreFile { version: KEYSTORE_VERSION, entries: self.entries.clone() };
+        let data = serde_json::to_string_pretty(&keystore_file).expect("Could not serialize keystore");
+
+        // written to a temporary file renamed over the keystore, so an interrupted write can not destroy the stored keys
+        let temp_path = self.path.with_extension("tmp");
+        let write_error = |err: std::io::Error| keystore_error(format!("could not write {}: {}", temp_path.display(), err).as_str());
+
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        let mut file = options.open(&temp_path).map_err(write_error)?;
+
+        // mode is applied only to new files, a temporary file left by an earlier run keeps its permissions
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            file.set_permissions(fs::Permissions::from_mode(0o600)).map_err(write_error)?;
+        }
+
+        std::io::Write::write_all(&mut file, data.as_bytes()).map_err(write_error)?;
+        file.sync_all().map_err(write_error)?;
+
+        fs::rename(&temp_path, &self.path)
+            .map_err(|err| keystore_error(format!("could not write {}: {}", self.path.display(), err).as_str()))
+    }
+
+    pub fn entries(&self) -> &Vec<KeystoreEntry> {
+        &self.entries
+    }
+
+    pub fn entry(&self, label: &str) -> Result<&KeystoreEntry, Error> {
+        self.entries
+            .iter()
+            .find(|entry| entry.label == label)
+            .ok_or(keystore_error(format!("key with label {} not found", label).as_str()))
+    }
+
+    pub fn import(&mut self,
+                  label: &str,
+                  private_key: &PrivateKey,
+                  public_key: &[u8],
+                  identity: Option<Identifier>,
+                  key_id: Option<u32>,
+                  password: &str) -> Result<(), Error> {
+        if self.entries.iter().any(|entry| entry.label == label) {
+            return Err(keystore_error(format!("key with label {} already exists", label).as_str()));
+        }
+
+        let mut salt = [0u8; 16];
+        let mut nonce = [0u8; 12];
+        OsRng.fill_bytes(&mut salt);
+        OsRng.fill_bytes(&mut nonce);
+
+        let cipher = Keystore::cipher(password, &salt)?;
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), private_key.to_bytes().as_slice())
+            .map_err(|_| keystore_error("could not encrypt private key"))?;
+
+        self.entries.push(KeystoreEntry {
+            label: String::from(label),
+            network: private_key.network.to_string(),
+            identity: identity.map(|identifier| identifier.to_string(Base58)),
+            key_id,
+            public_key: hex::encode(public_key),
+            salt: hex::encode(salt),
+            nonce: hex::encode(nonce),
+            ciphertext: hex::encode(ciphertext),
+        });
+
+        Ok(())
+    }
+
+    pub fn remove(&mut self, label: &str) -> Result<KeystoreEntry, Error> {
+        let position = self.entries
+            .iter()
+            .position(|entry| entry.label == label)
+            .ok_or(keystore_error(format!("key with label {} not found", label).as_str()))?;
+
+        Ok(self.entries.remove(position))
+    }
+
+    /// Decrypts the private key by its label
+    pub fn decrypt(&self, label: &str, password: &str) -> Result<PrivateKey, Error> {
+        let entry = self.entry(label)?;
+
+        let salt = hex::decode(&entry.salt).map_err(|_| keystore_error("corrupted salt"))?;
+        let nonce = hex::decode(&entry.nonce).map_err(|_| keystore_error("corrupted nonce"))?;
+        let ciphertext = hex::decode(&entry.ciphertext).map_err(|_| keystore_error("corrupted ciphertext"))?;
+        let network = Network::from_str(&entry.network).map_err(|_| keystore_error("corrupted network"))?;
+
+        let cipher = Keystore::cipher(password, &salt)?;
+        let private_key_bytes = cipher
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+            .map_err(|_| keystore_error(format!("wrong password for key {}", label).as_str()))?;
+
+        PrivateKey::from_slice(private_key_bytes.as_slice(), network)
+            .map_err(|_| keystore_error(format!("could not decode private key {}", label).as_str()))
+    }
+
+    /// Decrypts the private key that is going to be used on behalf of the given identity in the given network
+    pub fn unlock(&self, label: &str, identity: Option<Identifier>, network: Option<Network>) -> Result<PrivateKey, Error> {
+        let entry = self.entry(label)?;
+
+        if let (Some(key_identity), Some(identity)) = (&entry.identity, identity) {
+            if key_identity != &identity.to_string(Base58) {
+                return Err(keystore_error(format!("key {} belongs to identity {}", label, key_identity).as_str()));
+            }
+        }
+
+        if let Some(network) = network {
+            let key_network = Network::from_str(&entry.network).map_err(|_| keystore_error("corrupted network"))?;
+
+            if key_network != network {
+                return Err(keystore_error(format!("key {} belongs to network {}, not {}", label, key_network, network).as_str()));
+            }
+        }
+
+        let password = Keystore::read_password(format!("Password for key {}: ", label).as_str())?;
+
+        self.decrypt(label, &password)
+    }
+
+    /// Takes the password from PLATFORM_CLI_KEYSTORE_PASSWORD or asks for it in the terminal
+    pub fn read_password(prompt: &str) -> Result<String, Error> {
+        if let Ok(password) = env::var(KEYSTORE_PASSWORD_ENV) {
+            return Ok(password);
+        }
+
+        rpassword::prompt_password(prompt)
+            .map_err(|err| keystore_error(format!("could not read password: {}", err).as_str()))
+    }
+
+    fn cipher(password: &str, salt: &[u8]) -> Result<Aes256Gcm, Error> {
+        let params = Params::new(SCRYPT_LOG_N, SCRYPT_R, SCRYPT_P, 32)
+            .map_err(|_| keystore_error("invalid scrypt parameters"))?;
+
+        let mut key = [0u8; 32];
+        scrypt::scrypt(password.as_bytes(), salt, &params, &mut key)
+            .map_err(|_| keystore_error("could not derive encryption key"))?;
+
+        Ok(Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::test_private_key;
+    use super::*;
+
+    fn keystore_with_key(network: Network) -> (Keystore, PrivateKey) {
+        let private_key = test_private_key(7, network);
+        let mut keystore = Keystore { path: PathBuf::from("keystore.json"), entries: vec![] };
+
+        keystore.import("main", &private_key, &[2u8; 33], None, Some(0), "password").unwrap();
+
+        (keystore, private_key)
+    }
+
+    #[test]
+    fn decrypts_imported_key() {
+        let (keystore, private_key) = keystore_with_key(Network::Testnet);
+
+        let decrypted = keystore.decrypt("main", "password").unwrap();
+
+        assert_eq!(decrypted.to_bytes(), private_key.to_bytes());
+        assert_eq!(decrypted.network, Network::Testnet);
+    }
+
+    #[test]
+    fn rejects_wrong_password() {
+        let (keystore, _) = keystore_with_key(Network::Testnet);
+
+        assert!(keystore.decrypt("main", "wrong").is_err());
+    }
+
+    #[test]
+    fn rejects_key_of_another_network() {
+        let (keystore, _) = keystore_with_key(Network::Testnet);
+
+        let result = keystore.unlock("main", None, Some(Network::Dash));
+
+        assert!(matches!(result, Err(Error::KeystoreError(_))));
+    }
+
+    #[test]
+    fn rejects_duplicate_label() {
+        let (mut keystore, private_key) = keystore_with_key(Network::Testnet);
+
+        assert!(keystore.import("main", &private_key, &[2u8; 33], None, None, "password").is_err());
+    }
+
+    #[test]
+    fn save_replaces_keystore_with_owner_only_file() {
+        let dir = std::env::temp_dir().join(format!("platform-cli-keystore-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let (mut keystore, private_key) = keystore_with_key(Network::Testnet);
+        keystore.path = dir.join("keystore.json");
+        fs::write(&keystore.path, "{}").unwrap();
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&keystore.path, fs::Permissions::from_mode(0o644)).unwrap();
+        }
+
+        keystore.save().unwrap();
+
+        let reopened = Keystore::open(&keystore.path).unwrap();
+        assert_eq!(reopened.decrypt("main", "password").unwrap().to_bytes(), private_key.to_bytes());
+        assert!(!dir.join("keystore.tmp").exists());
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(fs::metadata(&keystore.path).unwrap().permissions().mode() & 0o777, 0o600);
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
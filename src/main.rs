@@ -6,55 +6,89 @@ pub(crate) mod utils;
 mod errors;
 mod logger;
 mod constants;
+mod core_rpc;
+mod config;
+mod context;
+mod flags;
+mod keystore;
+mod wallet;
+mod key_requirements;
+mod signer;
+mod dpns;
+mod output;
+mod nonce_manager;
+mod document_validator;
+#[cfg(test)]
+mod test_utils;
 
 use clap::{Parser, Subcommand};
-use dpp::{BlsModule, ProtocolError, PublicKeyValidationError};
 use crate::commands::masternode_vote_dpns_name::MasternodeVoteDPNSNameCommand;
 use crate::commands::register_dpns_name::RegisterDPNSNameCommand;
 use crate::commands::withdraw::WithdrawCommand;
+use crate::commands::withdrawal_status::WithdrawalStatusCommand;
+use crate::commands::transfer_credits::TransferCreditsCommand;
+use crate::commands::update_identity::UpdateIdentityCommand;
+use crate::commands::masternode_identity::MasternodeIdentityCommand;
+use crate::commands::keys::KeysCommand;
+use crate::commands::batch::BatchCommand;
+use crate::commands::dpns_name::DpnsNameCommand;
+use crate::commands::name_status::NameStatusCommand;
 use log::{info, LevelFilter};
 use crate::logger::Logger;
-
-pub struct MockBLS {}
-
-impl BlsModule for MockBLS {
-    fn validate_public_key(&self, pk: &[u8]) -> Result<(), PublicKeyValidationError> {
-        panic!("BLS signatures are not implemented");
-    }
-
-    fn verify_signature(&self, signature: &[u8], data: &[u8], public_key: &[u8]) -> Result<bool, ProtocolError> {
-        panic!("BLS signatures are not implemented");
-    }
-
-    fn private_key_to_public_key(&self, private_key: &[u8]) -> Result<Vec<u8>, ProtocolError> {
-        panic!("BLS signatures are not implemented");
-    }
-
-    fn sign(&self, data: &[u8], private_key: &[u8]) -> Result<Vec<u8>, ProtocolError> {
-        panic!("BLS signatures are not implemented");
-    }
-}
-
+use crate::context::Context;
+use crate::flags::GlobalFlags;
+use crate::output::{Output, OutputFormat};
 
 #[derive(Parser)]
 struct Args {
     #[command(subcommand)]
     cmd: MyCommand,
+
+    #[command(flatten)]
+    flags: GlobalFlags,
 }
 
 #[derive(Subcommand)]
 enum MyCommand {
     Withdraw(WithdrawCommand),
+    WithdrawalStatus(WithdrawalStatusCommand),
+    TransferCredits(TransferCreditsCommand),
+    UpdateIdentity(UpdateIdentityCommand),
     RegisterDPNSName(RegisterDPNSNameCommand),
-    MasternodeVoteDPNSName(MasternodeVoteDPNSNameCommand)
+    MasternodeVoteDPNSName(MasternodeVoteDPNSNameCommand),
+    DpnsName(DpnsNameCommand),
+    NameStatus(NameStatusCommand),
+    MasternodeIdentity(MasternodeIdentityCommand),
+    Batch(BatchCommand),
+    Keys(KeysCommand),
+}
+
+impl MyCommand {
+    fn name(&self) -> &'static str {
+        match self {
+            MyCommand::Withdraw(_) => "withdraw",
+            MyCommand::WithdrawalStatus(_) => "withdrawal-status",
+            MyCommand::TransferCredits(_) => "transfer-credits",
+            MyCommand::UpdateIdentity(_) => "update-identity",
+            MyCommand::RegisterDPNSName(_) => "register-dpns-name",
+            MyCommand::MasternodeVoteDPNSName(_) => "masternode-vote-dpns-name",
+            MyCommand::DpnsName(_) => "dpns-name",
+            MyCommand::NameStatus(_) => "name-status",
+            MyCommand::MasternodeIdentity(_) => "masternode-identity",
+            MyCommand::Batch(_) => "batch",
+            MyCommand::Keys(_) => "keys",
+        }
+    }
 }
 
 static LOGGER: Logger = Logger;
 
-async fn set_logging_level(verbose: bool) {
-    let level = match verbose {
-        true => LevelFilter::Debug,
-        false => LevelFilter::Info
+async fn set_logging_level(verbose: bool, output: OutputFormat) {
+    // JSON output must not be mixed with log lines
+    let level = match (output, verbose) {
+        (OutputFormat::Json, _) => LevelFilter::Off,
+        (OutputFormat::Text, true) => LevelFilter::Debug,
+        (OutputFormat::Text, false) => LevelFilter::Info
     };
 
     log::set_logger(&LOGGER)
@@ -65,29 +99,85 @@ async fn set_logging_level(verbose: bool) {
 async fn main() {
     const VERSION: &str = env!("CARGO_PKG_VERSION");
 
-    println!("platform-cli {} (https://github.com/pshenmic/platform-cli)", VERSION);
-
     let args = Args::parse();
+    let output = args.flags.output;
+
+    if output == OutputFormat::Text {
+        println!("platform-cli {} (https://github.com/pshenmic/platform-cli)", VERSION);
+    }
+
+    let context = match Context::new(&args.flags) {
+        Ok(context) => context,
+        Err(err) => {
+            let exit_code = err.exit_code();
+
+            Output::print(output, "", &Err(err));
+            std::process::exit(exit_code);
+        }
+    };
+
+    let command = args.cmd.name();
 
     let result = match args.cmd {
-        MyCommand::Withdraw(x) => {
-            set_logging_level(x.verbose).await;
-            x.run().await
+        MyCommand::Withdraw(mut x) => {
+            set_logging_level(x.verbose, output).await;
+            x.apply_profile(&context.profile);
+            x.run(&context).await
+        }
+        MyCommand::WithdrawalStatus(mut x) => {
+            set_logging_level(x.verbose, output).await;
+            x.apply_profile(&context.profile);
+            x.run(&context).await
+        }
+        MyCommand::TransferCredits(mut x) => {
+            set_logging_level(x.verbose, output).await;
+            x.apply_profile(&context.profile);
+            x.run(&context).await
         }
-        MyCommand::RegisterDPNSName(x) => {
-            set_logging_level(x.verbose).await;
-            x.run().await
+        MyCommand::UpdateIdentity(mut x) => {
+            set_logging_level(x.verbose, output).await;
+            x.apply_profile(&context.profile);
+            x.run(&context).await
+        }
+        MyCommand::RegisterDPNSName(mut x) => {
+            set_logging_level(x.verbose, output).await;
+            x.apply_profile(&context.profile);
+            x.run(&context).await
+        },
+        MyCommand::MasternodeVoteDPNSName(mut x) => {
+            set_logging_level(x.verbose, output).await;
+            x.apply_profile(&context.profile);
+            x.run(&context).await
         },
-        MyCommand::MasternodeVoteDPNSName(x) => {
-            set_logging_level(x.verbose).await;
-            x.run().await
+        MyCommand::DpnsName(mut x) => {
+            set_logging_level(x.verbose, output).await;
+            x.apply_profile(&context.profile);
+            x.run(&context).await
+        },
+        MyCommand::NameStatus(mut x) => {
+            set_logging_level(x.verbose, output).await;
+            x.apply_profile(&context.profile);
+            x.run(&context).await
+        },
+        MyCommand::MasternodeIdentity(mut x) => {
+            set_logging_level(x.verbose, output).await;
+            x.apply_profile(&context.profile);
+            x.run(&context).await
+        },
+        MyCommand::Batch(mut x) => {
+            set_logging_level(x.verbose, output).await;
+            x.apply_profile(&context.profile);
+            x.run(&context).await
+        },
+        MyCommand::Keys(x) => {
+            set_logging_level(x.verbose, output).await;
+            x.run(&context).await
         },
     };
 
-    match result {
-        Ok(_) => (),
-        Err(err) => {
-            println!("Error: {}", err)
-        }
-    };
+    Output::print(output, command, &result);
+
+    if let Err(err) = result {
+        std::process::exit(err.exit_code());
+    }
 }
\ No newline at end of file
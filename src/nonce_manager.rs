@@ -0,0 +1,329 @@
+use std::collections::HashMap;
+use std::fs;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use dpp::identifier::Identifier;
+use dpp::identity::identity_nonce::{IDENTITY_NONCE_VALUE_FILTER, MAX_MISSING_IDENTITY_REVISIONS};
+use dpp::platform_value::string_encoding::Encoding::Base58;
+use dpp::prelude::IdentityNonce;
+use log::debug;
+use serde::{Deserialize, Serialize};
+use crate::errors::Error;
+use crate::errors::nonce_error::NonceError;
+use crate::grpc::PlatformGRPCClient;
+
+const NONCE_STATE_VERSION: u32 = 1;
+
+/// Pending nonces not confirmed by the network in this time are considered failed
+const PENDING_NONCE_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Lock whose owner process can not be checked is considered left by a crashed process after this time.
+/// The lock is held across DAPI requests with their retries (backoff is capped at 30s per attempt)
+const STALE_LOCK_AGE: Duration = Duration::from_secs(10 * 60);
+
+/// Locks of exited processes are taken over at once, so waiting longer only covers slow DAPI requests of a running one
+const LOCK_TIMEOUT: Duration = Duration::from_secs(2 * 60);
+
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Highest nonce handed out locally, that may not be processed by the network yet
+#[derive(Serialize, Deserialize, Clone, Debug)]
+struct PendingNonce {
+    nonce: u64,
+    /// Unix time in seconds
+    updated_at: u64,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct NonceStateFile {
+    version: u32,
+    /// Keyed by identity
+    identities: HashMap<String, PendingNonce>,
+    /// Keyed by identity:contract
+    contracts: HashMap<String, PendingNonce>,
+}
+
+fn nonce_error(message: &str) -> Error {
+    Error::NonceError(NonceError::from(message))
+}
+
+fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_secs()).unwrap_or(0)
+}
+
+/// Hands out identity and identity contract nonces for new state transitions.
+/// Nonces used by transitions that are not processed yet are tracked in a local state file,
+/// so commands running back-to-back or in parallel for the same identity do not collide
+pub struct NonceManager {
+    path: PathBuf,
+}
+
+impl NonceManager {
+    pub fn new(path: &Path) -> NonceManager {
+        NonceManager { path: path.to_path_buf() }
+    }
+
+    pub async fn next_identity_nonce(&self, platform_grpc_client: &PlatformGRPCClient, identity: Identifier) -> Result<IdentityNonce, Error> {
+        let _lock = self.lock().await?;
+
+        let platform_nonce = platform_grpc_client.get_identity_nonce(identity).await?;
+
+        let key = identity.to_string(Base58);
+
+        let mut state = self.load()?;
+        let nonce = NonceManager::next(&mut state.identities, &key, platform_nonce, 1)?;
+        self.save(&state)?;
+
+        debug!("Identity nonce for identifier {} is {}, using {}", identity, platform_nonce & IDENTITY_NONCE_VALUE_FILTER, nonce);
+
+        Ok(nonce)
+    }
+
+    pub async fn next_identity_contract_nonce(&self, platform_grpc_client: &PlatformGRPCClient, identity: Identifier, data_contract: Identifier) -> Result<IdentityNonce, Error> {
+        let nonces = self.next_identity_contract_nonces(platform_grpc_client, identity, data_contract, 1).await?;
+
+        Ok(nonces[0])
+    }
+
+    /// Reserves sequential contract nonces for several document transitions at once
+    pub async fn next_identity_contract_nonces(&self, platform_grpc_client: &PlatformGRPCClient, identity: Identifier, data_contract: Identifier, count: u64) -> Result<Vec<IdentityNonce>, Error> {
+        let _lock = self.lock().await?;
+
+        let platform_nonce = platform_grpc_client.get_identity_contract_nonce(identity, data_contract).await?;
+
+        let key = format!("{}:{}", identity.to_string(Base58), data_contract.to_string(Base58));
+
+        let mut state = self.load()?;
+        let nonce = NonceManager::next(&mut state.contracts, &key, platform_nonce, count)?;
+        self.save(&state)?;
+
+        debug!("Identity contract nonce for identifier {} and contract {} is {}, using {} to {}", identity, data_contract, platform_nonce & IDENTITY_NONCE_VALUE_FILTER, nonce, nonce + count - 1);
+
+        Ok((nonce..nonce + count).collect())
+    }
+
+    /// Reconciles the local pending nonce with the network one and reserves `count` next nonces, returning the first one.
+    /// Platform accepts nonces at most MAX_MISSING_IDENTITY_REVISIONS ahead of the current one
+    fn next(pending: &mut HashMap<String, PendingNonce>, key: &str, platform_nonce: IdentityNonce, count: u64) -> Result<IdentityNonce, Error> {
+        let platform_nonce = platform_nonce & IDENTITY_NONCE_VALUE_FILTER;
+        let now = now();
+
+        let local_nonce = pending.get(key)
+            .filter(|pending_nonce| now.saturating_sub(pending_nonce.updated_at) < PENDING_NONCE_TTL.as_secs())
+            .map(|pending_nonce| pending_nonce.nonce)
+            .unwrap_or(0);
+
+        let nonce = platform_nonce.max(local_nonce) + 1;
+        let last_nonce = nonce + count.max(1) - 1;
+
+        if last_nonce - platform_nonce > MAX_MISSING_IDENTITY_REVISIONS {
+            return Err(nonce_error(format!("{} nonces are needed while {} state transitions are still pending, platform accepts at most {} nonces ahead of the current {}", count.max(1), nonce - platform_nonce - 1, MAX_MISSING_IDENTITY_REVISIONS, platform_nonce).as_str()));
+        }
+
+        pending.insert(String::from(key), PendingNonce { nonce: last_nonce, updated_at: now });
+
+        Ok(nonce)
+    }
+
+    fn load(&self) -> Result<NonceStateFile, Error> {
+        if !self.path.exists() {
+            return Ok(NonceStateFile { version: NONCE_STATE_VERSION, ..NonceStateFile::default() });
+        }
+
+        let data = fs::read_to_string(&self.path)
+            .map_err(|err| nonce_error(format!("could not read {}: {}", self.path.display(), err).as_str()))?;
+
+        let state: NonceStateFile = serde_json::from_str(&data)
+            .map_err(|err| nonce_error(format!("could not parse {}: {}", self.path.display(), err).as_str()))?;
+
+        if state.version != NONCE_STATE_VERSION {
+            return Err(nonce_error(format!("unsupported nonce state version {}", state.version).as_str()));
+        }
+
+        Ok(state)
+    }
+
+    /// Writes a temporary file and renames it over the state file, so a crash can not leave it truncated
+    fn save(&self, state: &NonceStateFile) -> Result<(), Error> {
+        let data = serde_json::to_string_pretty(state).expect("Could not serialize nonce state");
+        let temp_path = self.path.with_extension("tmp");
+
+        fs::write(&temp_path, data)
+            .map_err(|err| nonce_error(format!("could not write {}: {}", temp_path.display(), err).as_str()))?;
+
+        fs::rename(&temp_path, &self.path)
+            .map_err(|err| nonce_error(format!("could not write {}: {}", self.path.display(), err).as_str()))
+    }
+
+    /// Takes the lock file next to the state file, waiting while another process holds it
+    async fn lock(&self) -> Result<NonceLock, Error> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|err| nonce_error(format!("could not create {}: {}", parent.display(), err).as_str()))?;
+        }
+
+        let lock_path = self.path.with_extension("lock");
+        let started_at = SystemTime::now();
+
+        loop {
+            match OpenOptions::new().write(true).create_new(true).open(&lock_path) {
+                Ok(mut file) => {
+                    // the owner process is checked by waiting processes, so a lock left by Ctrl-C or a crash is taken over at once
+                    let _ = write!(file, "{}", std::process::id());
+
+                    return Ok(NonceLock { path: lock_path });
+                }
+                Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => {
+                    if NonceManager::is_stale_lock(&lock_path) {
+                        debug!("Removing stale nonce lock {}", lock_path.display());
+                        let _ = fs::remove_file(&lock_path);
+                        continue;
+                    }
+
+                    if started_at.elapsed().map(|elapsed| elapsed > LOCK_TIMEOUT).unwrap_or(false) {
+                        let owner = fs::read_to_string(&lock_path).unwrap_or_default();
+
+                        return Err(nonce_error(format!("timed out waiting for lock {} held by process {}, remove it if no other platform-cli command is running", lock_path.display(), owner.trim()).as_str()));
+                    }
+
+                    tokio::time::sleep(LOCK_RETRY_INTERVAL).await;
+                }
+                Err(err) => return Err(nonce_error(format!("could not create lock {}: {}", lock_path.display(), err).as_str())),
+            }
+        }
+    }
+}
+
+impl NonceManager {
+    /// Lock is stale when its owner process is gone, or when the owner can not be checked and the lock is too old
+    fn is_stale_lock(lock_path: &Path) -> bool {
+        let owner_alive = fs::read_to_string(lock_path)
+            .ok()
+            .and_then(|pid| pid.trim().parse::<u32>().ok())
+            .and_then(NonceManager::is_process_alive);
+
+        if let Some(owner_alive) = owner_alive {
+            return !owner_alive;
+        }
+
+        fs::metadata(lock_path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .map(|age| age > STALE_LOCK_AGE)
+            .unwrap_or(false)
+    }
+
+    #[cfg(unix)]
+    fn is_process_alive(pid: u32) -> Option<bool> {
+        if pid == std::process::id() {
+            return Some(true);
+        }
+
+        std::process::Command::new("kill")
+            .args(["-0", &pid.to_string()])
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .status()
+            .ok()
+            .map(|status| status.success())
+    }
+
+    #[cfg(not(unix))]
+    fn is_process_alive(_pid: u32) -> Option<bool> {
+        None
+    }
+}
+
+/// Removes the lock file when dropped
+struct NonceLock {
+    path: PathBuf,
+}
+
+impl Drop for NonceLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pending_nonce(nonce: u64, age: Duration) -> PendingNonce {
+        PendingNonce { nonce, updated_at: now() - age.as_secs() }
+    }
+
+    #[test]
+    fn next_starts_after_platform_nonce() {
+        let mut pending = HashMap::new();
+
+        assert_eq!(NonceManager::next(&mut pending, "identity", 5, 1).ok(), Some(6));
+        assert_eq!(pending["identity"].nonce, 6);
+    }
+
+    #[test]
+    fn next_continues_after_pending_nonce() {
+        let mut pending = HashMap::from([(String::from("identity"), pending_nonce(7, Duration::ZERO))]);
+
+        assert_eq!(NonceManager::next(&mut pending, "identity", 5, 3).ok(), Some(8));
+        assert_eq!(pending["identity"].nonce, 10);
+    }
+
+    #[test]
+    fn next_ignores_expired_pending_nonce() {
+        let mut pending = HashMap::from([(String::from("identity"), pending_nonce(7, PENDING_NONCE_TTL + Duration::from_secs(1)))]);
+
+        assert_eq!(NonceManager::next(&mut pending, "identity", 5, 1).ok(), Some(6));
+    }
+
+    #[test]
+    fn next_rejects_nonces_beyond_window() {
+        let mut pending = HashMap::from([(String::from("identity"), pending_nonce(5 + MAX_MISSING_IDENTITY_REVISIONS, Duration::ZERO))]);
+
+        assert!(NonceManager::next(&mut pending, "identity", 5, 1).is_err());
+        assert_eq!(pending["identity"].nonce, 5 + MAX_MISSING_IDENTITY_REVISIONS);
+    }
+
+    #[test]
+    fn lock_of_running_process_is_not_stale() {
+        let lock_path = std::env::temp_dir().join(format!("platform-cli-nonce-lock-test-{}.lock", std::process::id()));
+        fs::write(&lock_path, std::process::id().to_string()).unwrap();
+
+        assert!(!NonceManager::is_stale_lock(&lock_path));
+
+        fs::remove_file(&lock_path).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn lock_of_exited_process_is_stale() {
+        let mut child = std::process::Command::new("true").spawn().unwrap();
+        child.wait().unwrap();
+
+        let lock_path = std::env::temp_dir().join(format!("platform-cli-nonce-lock-test-{}.lock", child.id()));
+        fs::write(&lock_path, child.id().to_string()).unwrap();
+
+        assert!(NonceManager::is_stale_lock(&lock_path));
+
+        fs::remove_file(&lock_path).unwrap();
+    }
+
+    #[test]
+    fn save_replaces_state_file() {
+        let dir = std::env::temp_dir().join(format!("platform-cli-nonce-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let nonce_manager = NonceManager::new(&dir.join("nonces.json"));
+
+        let mut state = nonce_manager.load().ok().unwrap();
+        state.identities.insert(String::from("identity"), pending_nonce(3, Duration::ZERO));
+        nonce_manager.save(&state).ok().unwrap();
+
+        assert_eq!(nonce_manager.load().ok().unwrap().identities["identity"].nonce, 3);
+        assert!(!dir.join("nonces.tmp").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
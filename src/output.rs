@@ -0,0 +1,104 @@
+use clap::ValueEnum;
+use dpp::dashcore::secp256k1::hashes::hex::DisplayHex;
+use dpp::identifier::Identifier;
+use dpp::identity::KeyID;
+use dpp::platform_value::string_encoding::Encoding::Base58;
+use serde::Serialize;
+use serde_json::{json, Value};
+use crate::errors::Error;
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human readable log lines
+    Text,
+    /// Single JSON object per invocation, for scripts and CI
+    Json,
+}
+
+/// State transition signed and broadcasted by a command
+#[derive(Serialize, Debug, Clone)]
+pub struct TransitionReport {
+    /// Type of the state transition, ex. IdentityCreditWithdrawal
+    pub transition: String,
+    pub hash: String,
+    pub hex: String,
+    pub identity: String,
+    pub key_id: u32,
+    pub nonce: u64,
+    pub result: String,
+}
+
+impl TransitionReport {
+    pub fn broadcasted(transition: &str, hash: &str, buffer: &[u8], identity: Identifier, key_id: KeyID, nonce: u64) -> TransitionReport {
+        TransitionReport {
+            transition: String::from(transition),
+            hash: String::from(hash),
+            hex: buffer.to_lower_hex_string(),
+            identity: identity.to_string(Base58),
+            key_id,
+            nonce,
+            result: String::from("broadcasted"),
+        }
+    }
+}
+
+/// Machine-readable result of a command
+#[derive(Serialize, Debug, Default)]
+pub struct Report {
+    pub transitions: Vec<TransitionReport>,
+    /// Command specific data, ex. list of withdrawals
+    #[serde(skip_serializing_if = "Value::is_null")]
+    pub data: Value,
+}
+
+impl Report {
+    pub fn new() -> Report {
+        Report::default()
+    }
+
+    pub fn with_data(data: Value) -> Report {
+        Report {
+            transitions: vec![],
+            data,
+        }
+    }
+
+    pub fn add_transition(&mut self, transition: TransitionReport) {
+        self.transitions.push(transition);
+    }
+}
+
+pub struct Output;
+
+impl Output {
+    /// Prints the command result in JSON, in text mode the result has been logged by the command already
+    pub fn print(format: OutputFormat, command: &str, result: &Result<Report, Error>) {
+        match format {
+            OutputFormat::Text => {
+                if let Err(err) = result {
+                    println!("Error: {}", err)
+                }
+            }
+            OutputFormat::Json => {
+                let output = match result {
+                    Ok(report) => json!({
+                        "command": command,
+                        "success": true,
+                        "transitions": report.transitions,
+                        "data": report.data,
+                    }),
+                    Err(err) => json!({
+                        "command": command,
+                        "success": false,
+                        "error": {
+                            "code": err.code(),
+                            "message": err.to_string(),
+                        },
+                    }),
+                };
+
+                println!("{}", output);
+            }
+        }
+    }
+}
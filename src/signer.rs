@@ -0,0 +1,132 @@
+use dpp::BlsModule;
+use dpp::bls::native_bls::NativeBlsModule;
+use dpp::dashcore::hashes::{hash160, Hash};
+use dpp::dashcore::secp256k1::Secp256k1;
+use dpp::dashcore::{signer, PrivateKey};
+use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dpp::identity::{IdentityPublicKey, KeyType};
+use dpp::ProtocolError;
+use dpp::state_transition::StateTransition;
+use crate::errors::Error;
+use crate::errors::identity_public_key_requirement_error::IdentityPublicKeyRequirementError;
+
+/// Key types platform verifies state transition signatures of
+pub const SIGNING_KEY_TYPES: [KeyType; 3] = [KeyType::ECDSA_SECP256K1, KeyType::ECDSA_HASH160, KeyType::BLS12_381];
+
+/// Key types added to the identity with a signature proving the possession of the private key,
+/// platform expects an empty signature for hash key types
+pub const PROOF_OF_POSSESSION_KEY_TYPES: [KeyType; 2] = [KeyType::ECDSA_SECP256K1, KeyType::BLS12_381];
+
+pub struct Signer;
+
+impl Signer {
+    /// Signs the state transition with the private key of the identity key, according to the key type
+    pub fn sign(state_transition: &mut StateTransition, identity_public_key: &IdentityPublicKey, private_key: &PrivateKey) -> Result<(), Error> {
+        // platform does not verify EdDSA signatures of state transitions, they would be rejected after the broadcast
+        if !SIGNING_KEY_TYPES.contains(&identity_public_key.key_type()) {
+            return Err(Error::IdentityPublicKeyRequirementError(IdentityPublicKeyRequirementError::from(format!("Key id {} of type {} can not sign state transitions", identity_public_key.id(), identity_public_key.key_type()).as_str())));
+        }
+
+        Ok(state_transition.sign(identity_public_key, private_key.to_bytes().as_slice(), &NativeBlsModule)?)
+    }
+
+    /// Public key data stored in the identity key of the given type
+    pub fn public_key_data(key_type: KeyType, private_key: &PrivateKey) -> Result<Vec<u8>, ProtocolError> {
+        match key_type {
+            KeyType::ECDSA_SECP256K1 => Ok(private_key.public_key(&Secp256k1::new()).to_bytes()),
+            KeyType::ECDSA_HASH160 => Ok(private_key.public_key(&Secp256k1::new()).pubkey_hash().to_byte_array().to_vec()),
+            KeyType::BLS12_381 => NativeBlsModule.private_key_to_public_key(private_key.to_bytes().as_slice()),
+            KeyType::EDDSA_25519_HASH160 => {
+                let public_key = Signer::ed25519_signing_key(private_key).verifying_key().to_bytes();
+
+                Ok(hash160::Hash::hash(&public_key).to_byte_array().to_vec())
+            }
+            _ => Err(ProtocolError::Generic(format!("Key type {} is not supported", key_type)))
+        }
+    }
+
+    /// Signs arbitrary data with the private key according to the key type, ex. for a proof of key possession
+    pub fn sign_data(data: &[u8], key_type: KeyType, private_key: &PrivateKey) -> Result<Vec<u8>, ProtocolError> {
+        match key_type {
+            KeyType::ECDSA_SECP256K1 => signer::sign(data, private_key.to_bytes().as_slice())
+                .map(|signature| signature.to_vec())
+                .map_err(|err| ProtocolError::Generic(format!("Could not sign with ECDSA private key: {}", err))),
+            KeyType::BLS12_381 => NativeBlsModule.sign(data, private_key.to_bytes().as_slice()),
+            _ => Err(ProtocolError::Generic(format!("Key type {} does not sign data", key_type)))
+        }
+    }
+
+    pub fn ed25519_signing_key(private_key: &PrivateKey) -> ed25519_dalek::SigningKey {
+        ed25519_dalek::SigningKey::from_bytes(&private_key.inner.secret_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dpp::dashcore::Network;
+    use dpp::identifier::Identifier;
+    use dpp::identity::{Purpose, SecurityLevel};
+    use dpp::state_transition::identity_credit_withdrawal_transition::v1::IdentityCreditWithdrawalTransitionV1;
+    use dpp::withdrawal::Pooling;
+    use crate::test_utils::{test_identity_public_key, test_private_key};
+    use super::*;
+
+    #[test]
+    fn signs_data_with_proof_of_possession_key_types() {
+        let private_key = test_private_key(7, Network::Testnet);
+
+        for key_type in PROOF_OF_POSSESSION_KEY_TYPES {
+            assert!(Signer::sign_data(b"data", key_type, &private_key).is_ok());
+        }
+    }
+
+    #[test]
+    fn refuses_to_sign_data_with_hash_key_types() {
+        let private_key = test_private_key(7, Network::Testnet);
+
+        assert!(Signer::sign_data(b"data", KeyType::ECDSA_HASH160, &private_key).is_err());
+        assert!(Signer::sign_data(b"data", KeyType::EDDSA_25519_HASH160, &private_key).is_err());
+    }
+
+    #[test]
+    fn hash_key_data_is_20_bytes() {
+        let private_key = test_private_key(7, Network::Testnet);
+
+        assert_eq!(Signer::public_key_data(KeyType::ECDSA_HASH160, &private_key).unwrap().len(), 20);
+        assert_eq!(Signer::public_key_data(KeyType::EDDSA_25519_HASH160, &private_key).unwrap().len(), 20);
+        assert_eq!(Signer::public_key_data(KeyType::ECDSA_SECP256K1, &private_key).unwrap().len(), 33);
+    }
+
+    #[test]
+    fn bls_signature_verifies_with_public_key_data() {
+        let private_key = test_private_key(7, Network::Testnet);
+
+        let public_key = Signer::public_key_data(KeyType::BLS12_381, &private_key).unwrap();
+        let signature = Signer::sign_data(b"data", KeyType::BLS12_381, &private_key).unwrap();
+
+        assert_eq!(public_key.len(), 48);
+        assert!(NativeBlsModule.verify_signature(&signature, b"data", &public_key).unwrap());
+    }
+
+    #[test]
+    fn refuses_to_sign_state_transitions_with_eddsa_keys() {
+        let private_key = test_private_key(7, Network::Testnet);
+        let identity_public_key = test_identity_public_key(0, KeyType::EDDSA_25519_HASH160, Purpose::TRANSFER, SecurityLevel::CRITICAL, &private_key);
+
+        let mut state_transition = StateTransition::from(IdentityCreditWithdrawalTransitionV1 {
+            identity_id: Identifier::default(),
+            amount: 1000,
+            core_fee_per_byte: 1,
+            pooling: Pooling::Never,
+            output_script: None,
+            nonce: 1,
+            user_fee_increase: 0,
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        });
+
+        let result = Signer::sign(&mut state_transition, &identity_public_key, &private_key);
+
+        assert!(matches!(result, Err(Error::IdentityPublicKeyRequirementError(_))));
+    }
+}
@@ -0,0 +1,24 @@
+use dpp::dashcore::{Network, PrivateKey};
+use dpp::identity::identity_public_key::v0::IdentityPublicKeyV0;
+use dpp::identity::{IdentityPublicKey, KeyType, Purpose, SecurityLevel};
+use dpp::platform_value::BinaryData;
+use crate::signer::Signer;
+
+/// Private key filled with the byte, a valid secret for secp256k1, BLS12-381 and Ed25519 keys for small bytes
+pub fn test_private_key(byte: u8, network: Network) -> PrivateKey {
+    PrivateKey::from_slice(&[byte; 32], network).unwrap()
+}
+
+/// Identity key of the given type holding the public key data of the private key
+pub fn test_identity_public_key(id: u32, key_type: KeyType, purpose: Purpose, security_level: SecurityLevel, private_key: &PrivateKey) -> IdentityPublicKey {
+    IdentityPublicKey::V0(IdentityPublicKeyV0 {
+        id,
+        purpose,
+        security_level,
+        contract_bounds: None,
+        key_type,
+        read_only: false,
+        data: BinaryData::new(Signer::public_key_data(key_type, private_key).unwrap()),
+        disabled_at: None,
+    })
+}
@@ -1,11 +1,20 @@
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
 use anyhow::Context;
 use base64::Engine;
 use base64::engine::general_purpose;
-use dpp::dashcore::{Network, PrivateKey};
+use dpp::dashcore::{Address, AddressType, Network, PrivateKey, PubkeyHash};
+use dpp::dashcore::secp256k1::Secp256k1;
+use dpp::identifier::Identifier;
+use dpp::platform_value::string_encoding::Encoding::Base58;
+use dpp::identity::core_script::CoreScript;
 use dpp::util::entropy_generator::EntropyGenerator;
 use getrandom::getrandom;
 use crate::errors::cli_argument_invalid_input::CommandLineArgumentInvalidInput;
 use crate::errors::Error;
+use crate::keystore::Keystore;
+use crate::wallet::Wallet;
 
 
 pub struct MyDefaultEntropyGenerator;
@@ -21,28 +30,141 @@ impl EntropyGenerator for MyDefaultEntropyGenerator {
 pub struct Utils;
 
 impl Utils {
+    pub fn parse_network(network: &str) -> Result<Network, Error> {
+        let network = if network == "mainnet" { "dash" } else { network };
+
+        Network::from_str(network)
+            .map_err(|_| Error::CommandLineArgumentInvalidInput(CommandLineArgumentInvalidInput::from("Could not parse network, should be mainnet or testnet")))
+    }
+
+    pub fn parse_identifier(value: &str, name: &str) -> Result<Identifier, Error> {
+        Identifier::from_string(value, Base58)
+            .map_err(|_| Error::CommandLineArgumentInvalidInput(CommandLineArgumentInvalidInput::from(format!("Could not parse {} {}, it should be a base58 identifier", name, value).as_str())))
+    }
+
+    /// Parses Core address (P2PKH or P2SH) of the given network into the output script
+    pub fn parse_core_address(address: &str, network: Network) -> Result<CoreScript, Error> {
+        let address = Address::from_str(address)
+            .map_err(|_| Error::CommandLineArgumentInvalidInput(CommandLineArgumentInvalidInput::from(format!("Could not parse Core address {}", address).as_str())))?
+            .require_network(network)
+            .map_err(|_| Error::CommandLineArgumentInvalidInput(CommandLineArgumentInvalidInput::from(format!("Core address {} does not belong to {} network", address, network).as_str())))?;
+
+        match address.address_type() {
+            Some(AddressType::P2pkh) | Some(AddressType::P2sh) => Ok(CoreScript::from_bytes(address.script_pubkey().to_bytes())),
+            _ => Err(Error::CommandLineArgumentInvalidInput(CommandLineArgumentInvalidInput::from(format!("Core address {} must be P2PKH or P2SH", address).as_str())))
+        }
+    }
+
+    pub fn read_private_key_file(path: &str, network: Network) -> Result<PrivateKey, Error> {
+        let private_key_data = fs::read_to_string(path)
+            .map_err(|_| Error::CommandLineArgumentInvalidInput(CommandLineArgumentInvalidInput::from(format!("Unable to read private key file {}", path).as_str())))?;
+
+        Utils::decode_private_key_from_input_string(private_key_data.as_str(), network)
+    }
+
+    /// Loads signing key candidates from the mnemonic, the keystore or the private key files (in that order).
+    /// Several keystore labels or key files can be given separated by comma
+    pub fn load_private_keys(private_key_path: &str,
+                             key_label: &str,
+                             mnemonic_path: &str,
+                             identity_index: Option<u32>,
+                             identity: Option<Identifier>,
+                             keystore_path: &Path,
+                             network: Network) -> Result<Vec<PrivateKey>, Error> {
+        if !mnemonic_path.is_empty() {
+            let wallet = Wallet::from_mnemonic_file(mnemonic_path, network)?;
+
+            return Ok(wallet
+                .identity_authentication_keys(identity_index)?
+                .into_iter()
+                .map(|derived_key| derived_key.private_key)
+                .collect());
+        }
+
+        if !key_label.is_empty() {
+            let keystore = Keystore::open(keystore_path)?;
+
+            return key_label
+                .split(',')
+                .map(|label| keystore.unlock(label.trim(), identity, Some(network)))
+                .collect();
+        }
+
+        private_key_path
+            .split(',')
+            .map(|path| Utils::read_private_key_file(path.trim(), network))
+            .collect()
+    }
+
+    pub fn pubkey_hashes(private_keys: &[PrivateKey]) -> Vec<PubkeyHash> {
+        let secp = Secp256k1::new();
+
+        private_keys
+            .iter()
+            .map(|private_key| private_key.public_key(&secp).pubkey_hash())
+            .collect()
+    }
+
+    /// Formats unix time in milliseconds as UTC date and time, ex. 2024-10-01 12:00:00 UTC
+    pub fn format_timestamp_ms(timestamp_ms: u64) -> String {
+        let seconds = timestamp_ms / 1000;
+        let (days, time) = ((seconds / 86400) as i64, seconds % 86400);
+
+        // civil date from days since the unix epoch, proleptic gregorian calendar
+        let shifted = days + 719468;
+        let era = shifted.div_euclid(146097);
+        let day_of_era = shifted.rem_euclid(146097);
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+        let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        format!("{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC", year, month, day, time / 3600, time % 3600 / 60, time % 60)
+    }
+
     pub fn decode_private_key_from_input_string(input: &str, network: Network) -> Result<PrivateKey, Error> {
-        let trimmed_input = input.replace("\n", "");
+        let trimmed_input = Utils::tenderdash_private_key_value(input.trim())
+            .unwrap_or(input.replace("\n", ""));
 
         let base58: Vec<u8> = match PrivateKey::from_wif(&trimmed_input) {
             Ok(private_key) => private_key.to_bytes(),
             Err(_) => Vec::from([])
         };
         let hex: Vec<u8> = hex::decode(&trimmed_input).unwrap_or(Vec::from([]));
-        let base64: Vec<u8> = general_purpose::STANDARD.decode(hex::decode(&trimmed_input).unwrap_or(Vec::from([]))).unwrap_or(Vec::from([]));
+        let base64: Vec<u8> = general_purpose::STANDARD.decode(&trimmed_input).unwrap_or(Vec::from([]));
 
         let private_key: PrivateKey = {
             if base58.len() > 0 {
                 PrivateKey::from_wif(&trimmed_input).expect("Unexpected error, could not construct private key from hex after validation")
             } else if hex.len() > 0 {
-                PrivateKey::from_slice(hex.as_slice(), network).expect("Unexpected error, could not construct private key from hex after validation")
+                Utils::private_key_from_slice(hex.as_slice(), network)?
             } else if base64.len() > 0 {
-                PrivateKey::from_slice(base64.as_slice(), network).expect("Unexpected error, could not construct private key from base64 after validation")
+                Utils::private_key_from_slice(base64.as_slice(), network)?
             } else {
-                return Err(Error::CommandLineArgumentInvalidInput(CommandLineArgumentInvalidInput::from("Could not decode private key type from file (should be in WIF or hex)")))
+                return Err(Error::CommandLineArgumentInvalidInput(CommandLineArgumentInvalidInput::from("Could not decode private key type from file (should be in WIF, hex or base64)")))
             }
         };
 
         Ok(private_key)
     }
+
+    /// Accepts 32 bytes secret keys, and 64 bytes Ed25519 keypairs (secret key followed by public key)
+    fn private_key_from_slice(data: &[u8], network: Network) -> Result<PrivateKey, Error> {
+        let secret_key = match data.len() {
+            64 => &data[..32],
+            _ => data
+        };
+
+        PrivateKey::from_slice(secret_key, network)
+            .map_err(|_| Error::CommandLineArgumentInvalidInput(CommandLineArgumentInvalidInput::from("Private key must be 32 bytes long (or 64 bytes Ed25519 keypair)")))
+    }
+
+    /// Extracts the key from Tenderdash node_key.json / priv_validator_key.json formats
+    fn tenderdash_private_key_value(input: &str) -> Option<String> {
+        let json: serde_json::Value = serde_json::from_str(input).ok()?;
+
+        json["priv_key"]["value"].as_str().map(String::from)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,136 @@
+use std::fs;
+use bip39::Mnemonic;
+use dpp::dashcore::bip32::{ChildNumber, DerivationPath, ExtendedPrivKey};
+use dpp::dashcore::secp256k1::Secp256k1;
+use dpp::dashcore::{Network, PrivateKey};
+use crate::errors::cli_argument_invalid_input::CommandLineArgumentInvalidInput;
+use crate::errors::Error;
+
+/// How many identities and keys per identity are scanned when looking for a matching identity key
+pub const IDENTITY_INDEX_SCAN_LIMIT: u32 = 10;
+pub const KEY_INDEX_SCAN_LIMIT: u32 = 16;
+
+/// Identity authentication key derived from the mnemonic
+pub struct DerivedKey {
+    pub identity_index: u32,
+    pub key_index: u32,
+    pub path: DerivationPath,
+    pub private_key: PrivateKey,
+}
+
+/// HD wallet deriving identity keys from a BIP39 mnemonic along the DIP-13 paths
+pub struct Wallet {
+    master_key: ExtendedPrivKey,
+    network: Network,
+}
+
+impl Wallet {
+    pub fn from_mnemonic(phrase: &str, network: Network) -> Result<Wallet, Error> {
+        let mnemonic = Mnemonic::parse_normalized(phrase.trim())
+            .map_err(|err| Error::CommandLineArgumentInvalidInput(CommandLineArgumentInvalidInput::from(format!("Could not parse mnemonic: {}", err).as_str())))?;
+
+        let seed = mnemonic.to_seed("");
+
+        let master_key = ExtendedPrivKey::new_master(network, &seed)
+            .map_err(|_| Error::CommandLineArgumentInvalidInput(CommandLineArgumentInvalidInput::from("Could not derive master key from the mnemonic")))?;
+
+        Ok(Wallet { master_key, network })
+    }
+
+    /// Reads the mnemonic from the file, or asks for it in the terminal if the path is `-`
+    pub fn from_mnemonic_file(path: &str, network: Network) -> Result<Wallet, Error> {
+        let phrase = match path {
+            // prompted directly, PLATFORM_CLI_KEYSTORE_PASSWORD must never be taken for the mnemonic
+            "-" => rpassword::prompt_password("Mnemonic: ")
+                .map_err(|err| Error::CommandLineArgumentInvalidInput(CommandLineArgumentInvalidInput::from(format!("Could not read mnemonic: {}", err).as_str())))?,
+            _ => fs::read_to_string(path)
+                .map_err(|_| Error::CommandLineArgumentInvalidInput(CommandLineArgumentInvalidInput::from(format!("Unable to read mnemonic file {}", path).as_str())))?
+        };
+
+        Wallet::from_mnemonic(&phrase, network)
+    }
+
+    /// DIP-13 identity authentication key path m/9'/coin_type'/5'/0'/0'/identity_index'/key_index'
+    pub fn identity_authentication_path(&self, identity_index: u32, key_index: u32) -> DerivationPath {
+        let coin_type = match self.network {
+            Network::Dash => 5,
+            _ => 1
+        };
+
+        DerivationPath::from(vec![
+            ChildNumber::Hardened { index: 9 },
+            ChildNumber::Hardened { index: coin_type },
+            ChildNumber::Hardened { index: 5 },
+            ChildNumber::Hardened { index: 0 },
+            ChildNumber::Hardened { index: 0 },
+            ChildNumber::Hardened { index: identity_index },
+            ChildNumber::Hardened { index: key_index },
+        ])
+    }
+
+    pub fn derive_identity_authentication_key(&self, identity_index: u32, key_index: u32) -> Result<DerivedKey, Error> {
+        let secp = Secp256k1::new();
+        let path = self.identity_authentication_path(identity_index, key_index);
+
+        let extended_private_key = self.master_key
+            .derive_priv(&secp, &path)
+            .map_err(|_| Error::CommandLineArgumentInvalidInput(CommandLineArgumentInvalidInput::from(format!("Could not derive key {}", path).as_str())))?;
+
+        Ok(DerivedKey {
+            identity_index,
+            key_index,
+            path,
+            private_key: extended_private_key.to_priv(),
+        })
+    }
+
+    /// Derives first keys of the identity, or of the first identities if index is not known
+    pub fn identity_authentication_keys(&self, identity_index: Option<u32>) -> Result<Vec<DerivedKey>, Error> {
+        let identity_indexes = match identity_index {
+            Some(identity_index) => identity_index..identity_index + 1,
+            None => 0..IDENTITY_INDEX_SCAN_LIMIT
+        };
+
+        let mut derived_keys: Vec<DerivedKey> = vec![];
+
+        for identity_index in identity_indexes {
+            for key_index in 0..KEY_INDEX_SCAN_LIMIT {
+                derived_keys.push(self.derive_identity_authentication_key(identity_index, key_index)?);
+            }
+        }
+
+        Ok(derived_keys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn uses_dip13_identity_authentication_path() {
+        let testnet = Wallet::from_mnemonic(MNEMONIC, Network::Testnet).ok().unwrap();
+        let mainnet = Wallet::from_mnemonic(MNEMONIC, Network::Dash).ok().unwrap();
+
+        assert_eq!(testnet.identity_authentication_path(2, 3).to_string(), "m/9'/1'/5'/0'/0'/2'/3'");
+        assert_eq!(mainnet.identity_authentication_path(2, 3).to_string(), "m/9'/5'/5'/0'/0'/2'/3'");
+    }
+
+    #[test]
+    fn derives_same_key_from_same_mnemonic() {
+        let first = Wallet::from_mnemonic(MNEMONIC, Network::Testnet).ok().unwrap();
+        let second = Wallet::from_mnemonic(&format!("  {}\n", MNEMONIC), Network::Testnet).ok().unwrap();
+
+        let first_key = first.derive_identity_authentication_key(0, 1).ok().unwrap();
+        let second_key = second.derive_identity_authentication_key(0, 1).ok().unwrap();
+
+        assert_eq!(first_key.private_key.to_bytes(), second_key.private_key.to_bytes());
+    }
+
+    #[test]
+    fn rejects_invalid_mnemonic() {
+        assert!(Wallet::from_mnemonic("abandon abandon abandon", Network::Testnet).is_err());
+    }
+}